@@ -0,0 +1,224 @@
+use crate::css_enum;
+
+use super::{easing::{timing_parts, Easing}, quantities::Duration, selector::escape_identifier, CommaList, CssBlock, IntoCss};
+
+css_enum!(AnimationDirection; Normal | Reverse | Alternate | AlternateReverse "alternate-reverse");
+css_enum!(AnimationFillMode; None | Forwards | Backwards | Both);
+css_enum!(AnimationPlayState; Running | Paused);
+
+/// The name of the `@keyframes` an animation runs, or `none`
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnimationName {
+    None,
+    /// Escaped when it is serialized
+    Name(String),
+}
+
+impl IntoCss for AnimationName {
+    fn into_css(self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::Name(name) => escape_identifier(&name),
+        }
+    }
+}
+
+impl From<&str> for AnimationName {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<&str> for CommaList<AnimationName> {
+    fn from(name: &str) -> Self {
+        CommaList(vec![name.into()])
+    }
+}
+
+impl From<&Keyframes> for AnimationName {
+    fn from(keyframes: &Keyframes) -> Self {
+        Self::Name(keyframes.name.clone())
+    }
+}
+
+/// How often an animation runs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IterationCount {
+    Infinite,
+    Count(f64),
+}
+
+impl IntoCss for IterationCount {
+    fn into_css(self) -> String {
+        match self {
+            Self::Infinite => "infinite".to_string(),
+            Self::Count(count) => count.to_string(),
+        }
+    }
+}
+
+impl From<i64> for IterationCount {
+    fn from(count: i64) -> Self {
+        Self::Count(count as f64)
+    }
+}
+
+impl From<f64> for IterationCount {
+    fn from(count: f64) -> Self {
+        Self::Count(count)
+    }
+}
+
+impl From<i64> for CommaList<IterationCount> {
+    fn from(count: i64) -> Self {
+        CommaList(vec![count.into()])
+    }
+}
+
+impl From<f64> for CommaList<IterationCount> {
+    fn from(count: f64) -> Self {
+        CommaList(vec![count.into()])
+    }
+}
+
+/// The `animation` shorthand, the parts that are not set keep their initial value
+#[derive(Clone)]
+pub struct Animation {
+    pub name: AnimationName,
+    pub duration: Option<Duration>,
+    pub easing: Option<Easing>,
+    pub delay: Option<Duration>,
+    pub iteration_count: Option<IterationCount>,
+    pub direction: Option<AnimationDirection>,
+    pub fill_mode: Option<AnimationFillMode>,
+    pub play_state: Option<AnimationPlayState>,
+}
+
+impl Animation {
+    pub fn new(name: impl Into<AnimationName>, duration: impl Into<Duration>) -> Self {
+        Self {
+            name: name.into(),
+            duration: Some(duration.into()),
+            easing: None,
+            delay: None,
+            iteration_count: None,
+            direction: None,
+            fill_mode: None,
+            play_state: None,
+        }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    pub fn delay(mut self, delay: impl Into<Duration>) -> Self {
+        self.delay = Some(delay.into());
+        self
+    }
+
+    pub fn iteration_count(mut self, count: impl Into<IterationCount>) -> Self {
+        self.iteration_count = Some(count.into());
+        self
+    }
+
+    pub fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn fill_mode(mut self, fill_mode: AnimationFillMode) -> Self {
+        self.fill_mode = Some(fill_mode);
+        self
+    }
+
+    pub fn play_state(mut self, play_state: AnimationPlayState) -> Self {
+        self.play_state = Some(play_state);
+        self
+    }
+}
+
+impl IntoCss for Animation {
+    fn into_css(self) -> String {
+        let mut parts = Vec::new();
+        parts.extend(timing_parts(self.duration, self.easing, self.delay));
+        parts.extend([
+            self.iteration_count.into_css(),
+            self.direction.into_css(),
+            self.fill_mode.into_css(),
+            self.play_state.into_css(),
+            self.name.into_css(),
+        ]);
+        parts.retain(|part| !part.is_empty());
+        parts.join(" ")
+    }
+}
+
+/// An `@keyframes` rule, the frames are blocks at a percentage of the animation
+#[derive(Clone)]
+pub struct Keyframes {
+    pub name: String,
+    pub frames: Vec<(f64, CssBlock)>,
+}
+
+impl Keyframes {
+    pub fn new(name: &str, frames: Vec<(f64, CssBlock)>) -> Self {
+        Self { name: name.to_string(), frames }
+    }
+
+    /// Adds the frame at `percentage`
+    pub fn frame(mut self, percentage: f64, block: CssBlock) -> Self {
+        self.frames.push((percentage, block));
+        self
+    }
+}
+
+/// `@keyframes name {0% {...}100% {...}}`, only the declarations of the frames are used
+impl IntoCss for Keyframes {
+    fn into_css(self) -> String {
+        let frames: String = self.frames.iter().map(|(percentage, block)| format!("{percentage}% {{{block}}}")).collect();
+        format!("@keyframes {} {{{frames}}}", escape_identifier(&self.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css, style::easing::Easing};
+
+    use super::*;
+
+    #[test]
+    fn shorthand() {
+        let animation = Animation::new("spin", Duration::S(1.0))
+            .easing(Easing::EaseInOut)
+            .iteration_count(IterationCount::Infinite)
+            .direction(AnimationDirection::Alternate);
+        assert_eq!(animation.into_css(), "1s ease-in-out infinite alternate spin");
+        assert_eq!(Animation::new("fade", Duration::Ms(200.0)).delay(Duration::S(1.0)).into_css(), "200ms 1s fade");
+        let mut delayed = Animation::new(AnimationName::None, Duration::S(1.0)).delay(Duration::S(2.0)).fill_mode(AnimationFillMode::Both);
+        delayed.duration = None;
+        assert_eq!(delayed.into_css(), "0s 2s both none");
+    }
+
+    #[test]
+    fn lists() {
+        let block = css!{
+            animation: vec![Animation::new("fade", Duration::Ms(200.0)), Animation::new("spin", Duration::S(1.0))];
+            animation_name: "spin";
+            animation_iteration_count: 2;
+            animation_direction: vec![AnimationDirection::Normal, AnimationDirection::Reverse];
+        };
+        assert_eq!(block.animation.into_css(), "200ms fade, 1s spin");
+        assert_eq!(block.animation_name.into_css(), "spin");
+        assert_eq!(block.animation_iteration_count.into_css(), "2");
+        assert_eq!(block.animation_direction.into_css(), "normal, reverse");
+    }
+
+    #[test]
+    fn names_are_escaped() {
+        assert_eq!(AnimationName::from("2 fast").into_css(), "\\32 \\ fast");
+        let keyframes = Keyframes::new("a.b", vec![(0.0, css!{ width: 0; })]).frame(100.0, css!{ width: 1.0; });
+        assert_eq!(keyframes.into_css(), "@keyframes a\\.b {0% {width: 0px;}100% {width: 100%;}}");
+    }
+}
@@ -0,0 +1,227 @@
+use std::{mem::discriminant, ops::{Add, Sub, Mul, Div, Neg}};
+
+use super::{quantities::Length, IntoCss};
+
+/// A CSS math expression over lengths.
+/// 
+/// Expressions are usually not built directly but through the arithmetic operators on `Length`
+/// and the `min`, `max` and `clamp` functions of this module.
+/// Terms with the same unit are folded into a single length, so `Length::Px(10.0) + Length::Px(5.0)` is just `15px`.
+#[derive(Clone)]
+pub enum Calc {
+    Sum(Length, Length),
+    Difference(Length, Length),
+    Product(Length, f64),
+    Quotient(Length, f64),
+    Min(Vec<Length>),
+    Max(Vec<Length>),
+    Clamp(Length, Length, Length),
+}
+
+impl Calc {
+    fn is_arithmetic(&self) -> bool {
+        matches!(self, Self::Sum(..) | Self::Difference(..) | Self::Product(..) | Self::Quotient(..))
+    }
+
+    /// The expression without the surrounding `calc()`
+    fn expression(self) -> String {
+        match self {
+            Self::Sum(a, b) => format!("{} + {}", operand(a), operand(b)),
+            Self::Difference(a, b) => format!("{} - {}", operand(a), operand(b)),
+            Self::Product(a, b) => format!("{} * {b}", operand(a)),
+            Self::Quotient(a, b) => format!("{} / {b}", operand(a)),
+            Self::Min(values) => format!("min({})", arguments(values)),
+            Self::Max(values) => format!("max({})", arguments(values)),
+            Self::Clamp(min, value, max) => format!("clamp({})", arguments(vec![min, value, max])),
+        }
+    }
+}
+
+impl IntoCss for Calc {
+    fn into_css(self) -> String {
+        if self.is_arithmetic() {
+            format!("calc({})", self.expression())
+        } else {
+            self.expression()
+        }
+    }
+}
+
+/// A length used as the operand of an arithmetic expression, nested arithmetic is put in parentheses
+fn operand(length: Length) -> String {
+    match length {
+        Length::Calc(calc) if calc.is_arithmetic() => format!("({})", calc.expression()),
+        Length::Calc(calc) => calc.expression(),
+        length => length.into_css(),
+    }
+}
+
+/// A length used as an argument of `min()`, `max()` or `clamp()`, these accept arithmetic without `calc()`
+fn arguments(values: Vec<Length>) -> String {
+    values.into_iter()
+        .map(|length| match length {
+            Length::Calc(calc) => calc.expression(),
+            length => length.into_css(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl From<Calc> for Length {
+    fn from(calc: Calc) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+
+/// Applies `f` to the values of `a` and `b` if both are plain lengths of the same unit
+fn fold(a: &Length, b: &Length, f: impl FnOnce(f64, f64) -> f64) -> Option<Length> {
+    match (a.split(), b.split()) {
+        (Some((a_value, unit)), Some((b_value, _))) if discriminant(a) == discriminant(b) => Some(unit(f(a_value, b_value))),
+        _ => None,
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, other: Length) -> Length {
+        fold(&self, &other, |a, b| a + b).unwrap_or_else(|| Calc::Sum(self, other).into())
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, other: Length) -> Length {
+        fold(&self, &other, |a, b| a - b).unwrap_or_else(|| Calc::Difference(self, other).into())
+    }
+}
+
+/// Panics if `factor` is not finite
+impl Mul<f64> for Length {
+    type Output = Length;
+
+    fn mul(self, factor: f64) -> Length {
+        assert!(factor.is_finite(), "attempt to multiply a length by a non-finite number");
+        match self.split() {
+            Some((value, unit)) => unit(value * factor),
+            None => Calc::Product(self, factor).into(),
+        }
+    }
+}
+
+impl Mul<Length> for f64 {
+    type Output = Length;
+
+    fn mul(self, length: Length) -> Length {
+        length * self
+    }
+}
+
+/// Panics if `divisor` is zero or not finite like integer division does, see `Length::checked_div`
+impl Div<f64> for Length {
+    type Output = Length;
+
+    fn div(self, divisor: f64) -> Length {
+        self.checked_div(divisor).expect("attempt to divide a length by zero or a non-finite number")
+    }
+}
+
+impl Length {
+    /// `self / divisor`, `None` if `divisor` is zero or not finite
+    pub fn checked_div(self, divisor: f64) -> Option<Length> {
+        if divisor == 0.0 || !divisor.is_finite() {
+            return None;
+        }
+        Some(match self.split() {
+            Some((value, unit)) => unit(value / divisor),
+            None => Calc::Quotient(self, divisor).into(),
+        })
+    }
+}
+
+impl Neg for Length {
+    type Output = Length;
+
+    fn neg(self) -> Length {
+        self * -1.0
+    }
+}
+
+/// Folds `values` with `f` if they all are plain lengths of the same unit
+fn fold_all(values: &[Length], f: fn(f64, f64) -> f64) -> Option<Length> {
+    let (first, rest) = values.split_first()?;
+    rest.iter().try_fold(first.clone(), |acc, value| fold(&acc, value, f))
+}
+
+/// `min()` of the given lengths, `None` if there are none
+pub fn min(values: Vec<Length>) -> Option<Length> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(fold_all(&values, f64::min).unwrap_or_else(|| Calc::Min(values).into()))
+}
+
+/// `max()` of the given lengths, `None` if there are none
+pub fn max(values: Vec<Length>) -> Option<Length> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(fold_all(&values, f64::max).unwrap_or_else(|| Calc::Max(values).into()))
+}
+
+/// `clamp()` a length between a minimum and a maximum
+pub fn clamp(min: impl Into<Length>, value: impl Into<Length>, max: impl Into<Length>) -> Length {
+    let (min, value, max) = (min.into(), value.into(), max.into());
+    fold(&value, &max, f64::min)
+        .and_then(|value| fold(&value, &min, f64::max))
+        .unwrap_or_else(|| Calc::Clamp(min, value, max).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn css(length: Length) -> String {
+        length.into_css()
+    }
+
+    #[test]
+    fn folds_same_units() {
+        assert_eq!(css(Length::Px(10.0) + Length::Px(5.0)), "15px");
+        assert_eq!(css(Length::Rem(2.0) - Length::Rem(0.5)), "1.5rem");
+        assert_eq!(css(Length::Em(2.0) * 3.0), "6em");
+        assert_eq!(css(-Length::Vw(4.0)), "-4vw");
+    }
+
+    #[test]
+    fn mixed_units_become_calc() {
+        assert_eq!(css(Length::Percent(100.0) - Length::Rem(2.0)), "calc(100% - 2rem)");
+        assert_eq!(css((Length::Percent(100.0) - Length::Rem(2.0)) / 2.0), "calc((100% - 2rem) / 2)");
+        assert_eq!(css(Length::Px(1.0) + (Length::Vw(1.0) * 2.0) + Length::Em(1.0)), "calc((1px + 2vw) + 1em)");
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        assert_eq!(min(vec![Length::Px(3.0), Length::Px(1.0)]).map(css), Some("1px".to_string()));
+        assert_eq!(max(vec![Length::Px(3.0), Length::Vw(1.0)]).map(css), Some("max(3px, 1vw)".to_string()));
+        assert!(min(Vec::new()).is_none());
+        assert!(max(Vec::new()).is_none());
+        assert_eq!(css(clamp(Length::Rem(1.0), Length::Vw(2.5), Length::Rem(2.0))), "clamp(1rem, 2.5vw, 2rem)");
+        assert_eq!(css(clamp(Length::Px(1.0), Length::Px(5.0), Length::Px(3.0))), "3px");
+        assert_eq!(css(max(vec![Length::Percent(50.0), Length::Px(1.0) + Length::Em(1.0)]).unwrap()), "max(50%, 1px + 1em)");
+    }
+
+    #[test]
+    fn division_by_zero_is_rejected() {
+        assert!(Length::Px(1.0).checked_div(0.0).is_none());
+        assert!(Length::Px(1.0).checked_div(f64::NAN).is_none());
+        assert_eq!(Length::Px(1.0).checked_div(4.0).map(css), Some("0.25px".to_string()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_operator_panics_on_zero() {
+        let _ = Length::Px(1.0) / 0.0;
+    }
+}
@@ -1,49 +1,338 @@
 use std::fmt::Display;
 
-use super::IntoCss;
+use crate::css_enum;
 
-#[derive(Clone, Copy, Debug)]
+use super::{quantities::Angle, variable::Var, IntoCss};
+
+use self::convert::Channels;
+
+pub use self::{
+    contrast::WcagLevel,
+    mix::{ColorMix, HueInterpolation, InterpolationSpace},
+    parse::ParseColorError,
+    relative::{RelativeChannel, RelativeColor, RelativeFunction},
+    serialize::{ColorFormat, set_default_format},
+};
+
+pub(crate) use self::serialize::with_format;
+
+mod contrast;
+mod convert;
+mod mix;
+mod parse;
+mod relative;
+mod serialize;
+
+#[derive(Clone, Debug)]
 pub enum Color {
     RGB(u8, u8, u8),
     RGBA(u8, u8, u8, f64),
     Named(NamedColor),
-    Transparent
+    Transparent,
+    /// Hue in degrees, saturation and lightness in percent and alpha, opaque if `None`
+    HSL(f64, f64, f64, Option<f64>),
+    /// Hue in degrees, whiteness and blackness in percent and alpha, opaque if `None`
+    HWB(f64, f64, f64, Option<f64>),
+    /// CIE lightness (0 - 100), a and b axis and alpha, opaque if `None`
+    LAB(f64, f64, f64, Option<f64>),
+    /// CIE lightness (0 - 100), chroma, hue in degrees and alpha, opaque if `None`
+    LCH(f64, f64, f64, Option<f64>),
+    /// Oklab lightness (0 - 1), a and b axis and alpha, opaque if `None`
+    OKLAB(f64, f64, f64, Option<f64>),
+    /// Oklab lightness (0 - 1), chroma, hue in degrees and alpha, opaque if `None`
+    OKLCH(f64, f64, f64, Option<f64>),
+    /// `color()` in a predefined color space with three channels and alpha, opaque if `None`
+    Space(ColorSpace, f64, f64, f64, Option<f64>),
+    /// `currentColor`, the value of the `color` property
+    CurrentColor,
+    /// A color of the user agent or operating system, e.g. for forced colors mode
+    System(SystemColor),
+    /// `color-mix()`
+    Mix(Box<ColorMix>),
+    /// A color function relative to another color, e.g. `rgb(from var(--x) r g b / 50%)`
+    Relative(Box<RelativeColor>),
+    /// A `var()` reference, so mixes and relative colors can be based on custom properties
+    Var(Var<Color>),
 }
 
 impl IntoCss for Color {
+    /// Serializes the color in the format set with `set_default_format`
     fn into_css(self) -> String {
-        match self {
+        self.to_css_with(serialize::default_format())
+    }
+}
+
+impl Color {
+    /// The color function (or keyword) the color was created with
+    fn to_functional(&self) -> String {
+        match self.clone() {
             Self::RGB(r, g, b) => format!("rgb({r}, {g}, {b})"),
             Self::RGBA(r, g, b, a) => format!("rgba({r}, {g}, {b}, {a})"),
-            Self::Named(name) => format!("{name}"),
-            Self::Transparent => format!("transparent")
+            Self::Named(name) => name.to_string(),
+            Self::Transparent => "transparent".to_string(),
+            Self::HSL(h, s, l, None) => format!("hsl({h}, {s}%, {l}%)"),
+            Self::HSL(h, s, l, Some(a)) => format!("hsla({h}, {s}%, {l}%, {a})"),
+            Self::HWB(h, w, b, a) => format!("hwb({h} {w}% {b}%{})", alpha_suffix(a)),
+            Self::LAB(l, a, b, alpha) => format!("lab({l}% {a} {b}{})", alpha_suffix(alpha)),
+            Self::LCH(l, c, h, a) => format!("lch({l}% {c} {h}{})", alpha_suffix(a)),
+            Self::OKLAB(l, a, b, alpha) => format!("oklab({l} {a} {b}{})", alpha_suffix(alpha)),
+            Self::OKLCH(l, c, h, a) => format!("oklch({l} {c} {h}{})", alpha_suffix(a)),
+            Self::Space(space, r, g, b, a) => format!("color({} {r} {g} {b}{})", space.into_css(), alpha_suffix(a)),
+            Self::CurrentColor => "currentColor".to_string(),
+            Self::System(color) => color.into_css(),
+            Self::Mix(mix) => mix.into_css(),
+            Self::Relative(color) => color.into_css(),
+            Self::Var(var) => var.into_css(),
         }
     }
 }
 
+/// The optional ` / alpha` part of the space separated color functions
+fn alpha_suffix(alpha: Option<f64>) -> String {
+    match alpha {
+        Some(alpha) => format!(" / {alpha}"),
+        None => String::new(),
+    }
+}
+
+/// The alpha of a color function, `None` if the color is opaque
+fn optional_alpha(alpha: f64) -> Option<f64> {
+    if alpha < 1.0 {
+        Some(alpha)
+    } else {
+        None
+    }
+}
+
+/// A color function with three channels, to create colors from tuples like `(ColorFunction::Oklch, 0.7, 0.1, 250.0)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorFunction {
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+css_enum!(ColorSpace;
+    Srgb | SrgbLinear "srgb-linear" | DisplayP3 "display-p3" | A98Rgb "a98-rgb" |
+    ProphotoRgb "prophoto-rgb" | Rec2020 | XyzD50 "xyz-d50" | XyzD65 "xyz-d65"
+);
+
+css_enum!(SystemColor;
+    AccentColor "AccentColor" | AccentColorText "AccentColorText" | ActiveText "ActiveText" |
+    ButtonBorder "ButtonBorder" | ButtonFace "ButtonFace" | ButtonText "ButtonText" |
+    Canvas "Canvas" | CanvasText "CanvasText" | Field "Field" | FieldText "FieldText" |
+    GrayText "GrayText" | Highlight "Highlight" | HighlightText "HighlightText" | LinkText "LinkText" |
+    Mark "Mark" | MarkText "MarkText" | SelectedItem "SelectedItem" | SelectedItemText "SelectedItemText" |
+    VisitedText "VisitedText"
+);
+
+impl Color {
+    pub fn hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self::HSL(hue, saturation, lightness, None)
+    }
+
+    pub fn hwb(hue: f64, whiteness: f64, blackness: f64) -> Self {
+        Self::HWB(hue, whiteness, blackness, None)
+    }
+
+    pub fn lab(lightness: f64, a: f64, b: f64) -> Self {
+        Self::LAB(lightness, a, b, None)
+    }
+
+    pub fn lch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        Self::LCH(lightness, chroma, hue, None)
+    }
+
+    pub fn oklab(lightness: f64, a: f64, b: f64) -> Self {
+        Self::OKLAB(lightness, a, b, None)
+    }
+
+    pub fn oklch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        Self::OKLCH(lightness, chroma, hue, None)
+    }
+}
+
+/// The lowercase css name of the color
 impl Display for NamedColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(&format!("{:?}", self).to_ascii_lowercase())
+    }
+}
+
+impl IntoCss for NamedColor {
+    fn into_css(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Conversion and manipulation.
+/// 
+/// All of these return `None` for colors that can only be resolved by the browser.
+impl Color {
+    /// The sRGB channels (0 - 1, not clamped to the gamut) and alpha
+    fn srgb_channels(&self) -> Option<(Channels, f64)> {
+        let rgb = |r: u8, g: u8, b: u8| [r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0];
+        match *self {
+            Self::RGB(r, g, b) => Some((rgb(r, g, b), 1.0)),
+            Self::RGBA(r, g, b, a) => Some((rgb(r, g, b), a)),
+            Self::Named(name) => {
+                let (r, g, b) = name.rgb();
+                Some((rgb(r, g, b), 1.0))
+            },
+            Self::Transparent => Some(([0.0; 3], 0.0)),
+            Self::HSL(h, s, l, a) => Some((convert::hsl_to_srgb([h, s, l]), a.unwrap_or(1.0))),
+            Self::HWB(h, w, b, a) => Some((convert::hwb_to_srgb([h, w, b]), a.unwrap_or(1.0))),
+            Self::Mix(ref mix) => mix.resolve()?.srgb_channels(),
+            Self::CurrentColor | Self::System(_) | Self::Relative(_) | Self::Var(_) => None,
+            _ => self.xyz().map(|(xyz, a)| (convert::xyz_to_space(ColorSpace::Srgb, xyz), a)),
+        }
+    }
+
+    /// The CIE XYZ (D65) channels and alpha
+    fn xyz(&self) -> Option<(Channels, f64)> {
+        match *self {
+            Self::LAB(l, a, b, alpha) => Some((convert::lab_to_xyz([l, a, b]), alpha.unwrap_or(1.0))),
+            Self::LCH(l, c, h, a) => Some((convert::lab_to_xyz(convert::lch_to_lab([l, c, h])), a.unwrap_or(1.0))),
+            Self::OKLAB(l, a, b, alpha) => Some((convert::oklab_to_xyz([l, a, b]), alpha.unwrap_or(1.0))),
+            Self::OKLCH(l, c, h, a) => Some((convert::oklab_to_xyz(convert::lch_to_lab([l, c, h])), a.unwrap_or(1.0))),
+            Self::Space(space, r, g, b, a) => Some((convert::space_to_xyz(space, [r, g, b]), a.unwrap_or(1.0))),
+            _ => self.srgb_channels().map(|(rgb, a)| (convert::space_to_xyz(ColorSpace::Srgb, rgb), a)),
+        }
+    }
+
+    /// The alpha of the color
+    pub fn alpha(&self) -> Option<f64> {
+        self.srgb_channels().map(|(_, a)| a)
+    }
+
+    /// Converts the color to `Color::RGB`, or `Color::RGBA` if it is not opaque.
+    /// 
+    /// Colors outside of the sRGB gamut are clamped.
+    pub fn to_srgb(&self) -> Option<Color> {
+        self.srgb_channels().map(|(rgb, a)| from_srgb(rgb, a))
+    }
+
+    /// Converts the color to `Color::HSL`
+    pub fn to_hsl(&self) -> Option<Color> {
+        self.srgb_channels().map(|(rgb, a)| from_hsl(convert::srgb_to_hsl(clamp(rgb)), a))
+    }
+
+    /// Converts the color to `Color::HWB`
+    pub fn to_hwb(&self) -> Option<Color> {
+        self.srgb_channels().map(|(rgb, a)| {
+            let [h, w, b] = convert::srgb_to_hwb(clamp(rgb)).map(|v| round(v, 2));
+            Self::HWB(h, w, b, optional_alpha(a))
+        })
+    }
+
+    /// Converts the color to `Color::LAB`
+    pub fn to_lab(&self) -> Option<Color> {
+        self.xyz().map(|(xyz, alpha)| {
+            let [l, a, b] = convert::xyz_to_lab(xyz).map(|v| round(v, 2));
+            Self::LAB(l, a, b, optional_alpha(alpha))
+        })
+    }
+
+    /// Converts the color to `Color::OKLCH`
+    pub fn to_oklch(&self) -> Option<Color> {
+        self.xyz().map(|(xyz, a)| {
+            let [l, c, h] = convert::lab_to_lch(convert::xyz_to_oklab(xyz));
+            Self::OKLCH(round(l, 4), round(c, 4), round(h, 2), optional_alpha(a))
+        })
+    }
+
+    /// Changes the HSL lightness by `amount` percentage points, the result is in HSL
+    pub fn lighten(&self, amount: f64) -> Option<Color> {
+        self.srgb_channels().map(|(rgb, a)| {
+            let [h, s, l] = convert::srgb_to_hsl(clamp(rgb));
+            from_hsl([h, s, (l + amount).clamp(0.0, 100.0)], a)
+        })
+    }
+
+    /// Changes the HSL lightness by `-amount` percentage points, the result is in HSL
+    pub fn darken(&self, amount: f64) -> Option<Color> {
+        self.lighten(-amount)
+    }
+
+    /// Changes the HSL saturation by `amount` percentage points, the result is in HSL
+    pub fn saturate(&self, amount: f64) -> Option<Color> {
+        self.srgb_channels().map(|(rgb, a)| {
+            let [h, s, l] = convert::srgb_to_hsl(clamp(rgb));
+            from_hsl([h, (s + amount).clamp(0.0, 100.0), l], a)
+        })
+    }
+
+    /// Changes the HSL saturation by `-amount` percentage points, the result is in HSL
+    pub fn desaturate(&self, amount: f64) -> Option<Color> {
+        self.saturate(-amount)
+    }
+
+    /// Mixes in `weight` (0 - 1) of `other`.
+    /// 
+    /// The colors are interpolated in Oklab with premultiplied alpha, the result is in sRGB.
+    pub fn mix(&self, other: &Color, weight: f64) -> Option<Color> {
+        let (a, a_alpha) = self.xyz()?;
+        let (b, b_alpha) = other.xyz()?;
+        let (a, b) = (convert::xyz_to_oklab(a), convert::xyz_to_oklab(b));
+        let alpha = a_alpha * (1.0 - weight) + b_alpha * weight;
+        if alpha == 0.0 {
+            return Some(Self::RGBA(0, 0, 0, 0.0));
+        }
+        let oklab = [0, 1, 2].map(|i| (a[i] * a_alpha * (1.0 - weight) + b[i] * b_alpha * weight) / alpha);
+        Some(from_srgb(convert::xyz_to_space(ColorSpace::Srgb, convert::oklab_to_xyz(oklab)), alpha))
+    }
+
+    /// The same color with a different alpha, keeping the color function where it has an alpha channel
+    pub fn with_alpha(&self, alpha: f64) -> Option<Color> {
+        match *self {
+            Self::RGB(r, g, b) | Self::RGBA(r, g, b, _) => Some(Self::RGBA(r, g, b, alpha)),
+            Self::HSL(h, s, l, _) => Some(Self::HSL(h, s, l, Some(alpha))),
+            Self::HWB(h, w, b, _) => Some(Self::HWB(h, w, b, Some(alpha))),
+            Self::LAB(l, a, b, _) => Some(Self::LAB(l, a, b, Some(alpha))),
+            Self::LCH(l, c, h, _) => Some(Self::LCH(l, c, h, Some(alpha))),
+            Self::OKLAB(l, a, b, _) => Some(Self::OKLAB(l, a, b, Some(alpha))),
+            Self::OKLCH(l, c, h, _) => Some(Self::OKLCH(l, c, h, Some(alpha))),
+            Self::Space(space, r, g, b, _) => Some(Self::Space(space, r, g, b, Some(alpha))),
+            _ => self.srgb_channels().map(|(rgb, _)| match from_srgb(rgb, 1.0) {
+                Self::RGB(r, g, b) => Self::RGBA(r, g, b, alpha),
+                x => x,
+            }),
+        }
+    }
+
+    /// Inverts the sRGB channels, the result is in sRGB
+    pub fn invert(&self) -> Option<Color> {
+        self.srgb_channels().map(|(rgb, a)| from_srgb(clamp(rgb).map(|v| 1.0 - v), a))
     }
 }
 
-trait ToRGB {
-    fn to_rgb(self) -> Color;
+fn clamp(channels: Channels) -> Channels {
+    channels.map(|v| v.clamp(0.0, 1.0))
 }
 
-trait ToRGBA {
-    fn to_rgba(self) -> Color;
+fn round(value: f64, digits: i32) -> f64 {
+    let factor = 10f64.powi(digits);
+    // adding zero turns -0 into 0
+    (value * factor).round() / factor + 0.0
 }
 
-impl<T: ToRGB> ToRGBA for T {
-    fn to_rgba(self) -> Color {
-        match self.to_rgb() {
-            Color::RGB(r, g, b) => Color::RGBA(r, g, b, 1.0),
-            x => panic!("{}.to_rgb() returned {:?}", stringify!(T), x)
-        }        
+fn from_srgb(rgb: Channels, alpha: f64) -> Color {
+    let [r, g, b] = clamp(rgb).map(|v| (v * 255.0).round() as u8);
+    if alpha < 1.0 {
+        Color::RGBA(r, g, b, alpha)
+    } else {
+        Color::RGB(r, g, b)
     }
 }
 
+fn from_hsl(hsl: Channels, alpha: f64) -> Color {
+    let [h, s, l] = hsl.map(|v| round(v, 2));
+    Color::HSL(h % 360.0, s, l, optional_alpha(alpha))
+}
+
 macro_rules! impl_NamedColor {
     ($($name:ident rgb($r:literal, $g:literal, $b:literal)),*) => {
         #[derive(Clone, Copy, Debug)]
@@ -51,10 +340,22 @@ macro_rules! impl_NamedColor {
             $($name),*
         }
 
-        impl ToRGB for NamedColor {
-            fn to_rgb(self) -> Color {
+        impl NamedColor {
+            /// All named colors
+            pub const ALL: &'static [NamedColor] = &[$(NamedColor::$name),*];
+
+            /// The sRGB channels of the named color
+            pub fn rgb(self) -> (u8, u8, u8) {
                 match self {
-                    $(NamedColor::$name => Color::RGB($r, $g, $b)),*
+                    $(NamedColor::$name => ($r, $g, $b)),*
+                }
+            }
+
+            /// Looks up a named color, ignoring case
+            pub fn from_name(name: &str) -> Option<NamedColor> {
+                match name {
+                    $(_ if name.eq_ignore_ascii_case(stringify!($name)) => Some(NamedColor::$name),)*
+                    _ => None
                 }
             }
         }
@@ -67,6 +368,18 @@ impl From<NamedColor> for Color {
     }
 }
 
+impl From<SystemColor> for Color {
+    fn from(color: SystemColor) -> Self {
+        Self::System(color)
+    }
+}
+
+impl From<Var<Color>> for Color {
+    fn from(var: Var<Color>) -> Self {
+        Self::Var(var)
+    }
+}
+
 impl From<(u8, u8, u8)> for Color {
     fn from(rgb: (u8, u8, u8)) -> Self {
         Self::RGB(rgb.0, rgb.1, rgb.2)
@@ -79,6 +392,56 @@ impl From<(u8, u8, u8, f64)> for Color {
     }
 }
 
+impl From<(Angle, f64, f64)> for Color {
+    fn from(hsl: (Angle, f64, f64)) -> Self {
+        Self::HSL(hsl.0.to_deg(), hsl.1, hsl.2, None)
+    }
+}
+
+impl From<(Angle, f64, f64, f64)> for Color {
+    fn from(hsl: (Angle, f64, f64, f64)) -> Self {
+        Self::HSL(hsl.0.to_deg(), hsl.1, hsl.2, Some(hsl.3))
+    }
+}
+
+impl From<(ColorSpace, f64, f64, f64)> for Color {
+    fn from(color: (ColorSpace, f64, f64, f64)) -> Self {
+        Self::Space(color.0, color.1, color.2, color.3, None)
+    }
+}
+
+impl From<(ColorSpace, f64, f64, f64, f64)> for Color {
+    fn from(color: (ColorSpace, f64, f64, f64, f64)) -> Self {
+        Self::Space(color.0, color.1, color.2, color.3, Some(color.4))
+    }
+}
+
+/// The channels in the order of the color function, hues in degrees
+impl From<(ColorFunction, f64, f64, f64)> for Color {
+    fn from((function, x, y, z): (ColorFunction, f64, f64, f64)) -> Self {
+        Color::from_function(function, x, y, z, None)
+    }
+}
+
+impl From<(ColorFunction, f64, f64, f64, f64)> for Color {
+    fn from((function, x, y, z, alpha): (ColorFunction, f64, f64, f64, f64)) -> Self {
+        Color::from_function(function, x, y, z, Some(alpha))
+    }
+}
+
+impl Color {
+    fn from_function(function: ColorFunction, x: f64, y: f64, z: f64, alpha: Option<f64>) -> Self {
+        match function {
+            ColorFunction::Hsl => Self::HSL(x, y, z, alpha),
+            ColorFunction::Hwb => Self::HWB(x, y, z, alpha),
+            ColorFunction::Lab => Self::LAB(x, y, z, alpha),
+            ColorFunction::Lch => Self::LCH(x, y, z, alpha),
+            ColorFunction::Oklab => Self::OKLAB(x, y, z, alpha),
+            ColorFunction::Oklch => Self::OKLCH(x, y, z, alpha),
+        }
+    }
+}
+
 impl_NamedColor! {
     Aliceblue	rgb(240, 248, 255),
     Antiquewhite	rgb(250, 235, 215),
@@ -199,6 +562,7 @@ impl_NamedColor! {
     Plum	rgb(221, 160, 221),
     Powderblue	rgb(176, 224, 230),
     Purple	rgb(128, 0, 128),
+    Rebeccapurple	rgb(102, 51, 153),
     Red	rgb(255, 0, 0),
     Rosybrown	rgb(188, 143, 143),
     Royalblue	rgb( 65, 105, 225),
@@ -227,4 +591,69 @@ impl_NamedColor! {
     Whitesmoke	rgb(245, 245, 245),
     Yellow	rgb(255, 255, 0),
     Yellowgreen rgb(154, 205, 50)
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn css(color: impl Into<Color>) -> String {
+        color.into().into_css()
+    }
+
+    #[test]
+    fn tuples_create_the_color_functions() {
+        assert_eq!(css((255, 0, 0)), "rgb(255, 0, 0)");
+        assert_eq!(css((Angle::Turn(0.5), 50.0, 25.0)), "hsl(180, 50%, 25%)");
+        assert_eq!(css((Angle::Deg(120.0), 50.0, 25.0, 0.5)), "hsla(120, 50%, 25%, 0.5)");
+        assert_eq!(css((ColorFunction::Hwb, 90.0, 10.0, 20.0)), "hwb(90 10% 20%)");
+        assert_eq!(css((ColorFunction::Lab, 50.0, 20.0, -30.0, 0.25)), "lab(50% 20 -30 / 0.25)");
+        assert_eq!(css((ColorFunction::Lch, 60.0, 40.0, 200.0)), "lch(60% 40 200)");
+        assert_eq!(css((ColorFunction::Oklab, 0.5, 0.1, -0.1)), "oklab(0.5 0.1 -0.1)");
+        assert_eq!(css((ColorFunction::Oklch, 0.7, 0.1, 250.0, 0.5)), "oklch(0.7 0.1 250 / 0.5)");
+    }
+
+    #[test]
+    fn every_color_function_has_an_optional_alpha() {
+        let colors = [Color::hsl(0.0, 100.0, 50.0), Color::hwb(0.0, 0.0, 0.0), Color::oklch(0.63, 0.26, 29.23)];
+        for color in colors.iter() {
+            assert_eq!(color.alpha(), Some(1.0));
+            assert_eq!(color.with_alpha(0.5).and_then(|color| color.alpha()), Some(0.5));
+        }
+        assert_eq!(css(Color::hsl(0.0, 100.0, 50.0).with_alpha(0.5).unwrap()), "hsla(0, 100%, 50%, 0.5)");
+        assert_eq!(css(Color::hwb(0.0, 0.0, 0.0).with_alpha(0.5).unwrap()), "hwb(0 0% 0% / 0.5)");
+    }
+
+    #[test]
+    fn conversions() {
+        let red = Color::from(NamedColor::Red);
+        assert_eq!(css(red.to_hsl().unwrap()), "hsl(0, 100%, 50%)");
+        assert_eq!(css(red.to_hwb().unwrap()), "hwb(0 0% 0%)");
+        assert_eq!(css(red.to_lab().unwrap()), "lab(54.29% 80.8 69.89)");
+        assert_eq!(css(red.to_oklch().unwrap()), "oklch(0.628 0.2577 29.23)");
+        assert_eq!(css(Color::hsl(120.0, 100.0, 25.0).to_srgb().unwrap()), "rgb(0, 128, 0)");
+        assert_eq!(css(Color::oklch(0.628, 0.2577, 29.23).to_srgb().unwrap()), "rgb(255, 0, 0)");
+        assert_eq!(css(Color::RGBA(255, 0, 0, 0.5).to_hsl().unwrap()), "hsla(0, 100%, 50%, 0.5)");
+        assert!(Color::CurrentColor.to_srgb().is_none());
+    }
+
+    #[test]
+    fn browser_colors() {
+        assert_eq!(css(Color::CurrentColor), "currentColor");
+        assert_eq!(css(SystemColor::ButtonText), "ButtonText");
+        for color in [Color::CurrentColor, SystemColor::Canvas.into()].iter() {
+            assert!(color.to_srgb().is_none());
+            assert!(color.alpha().is_none());
+            assert!(color.lighten(10.0).is_none());
+            assert!(color.with_alpha(0.5).is_none());
+            assert!(color.to_hex().is_none());
+        }
+    }
+
+    #[test]
+    fn manipulation() {
+        let gray = Color::RGB(128, 128, 128);
+        assert_eq!(css(gray.lighten(10.0).unwrap()), "hsl(0, 0%, 60.2%)");
+        assert_eq!(css(gray.invert().unwrap()), "rgb(127, 127, 127)");
+        assert_eq!(css(Color::RGB(0, 0, 0).mix(&Color::RGB(255, 255, 255), 0.0).unwrap()), "rgb(0, 0, 0)");
+    }
+}
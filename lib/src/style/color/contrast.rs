@@ -0,0 +1,146 @@
+//! Relative luminance and contrast ratio as defined by WCAG 2.x
+//! (https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+
+use super::{convert, Color};
+
+/// The WCAG 2.x conformance levels for the contrast of text (success criteria 1.4.3 and 1.4.6)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WcagLevel {
+    /// 4.5:1
+    AA,
+    /// 3:1, for large text (at least 18pt or 14pt bold)
+    AALargeText,
+    /// 7:1
+    AAA,
+    /// 4.5:1, for large text (at least 18pt or 14pt bold)
+    AAALargeText,
+}
+
+impl WcagLevel {
+    /// The minimum contrast ratio of the level
+    pub fn min_ratio(self) -> f64 {
+        match self {
+            Self::AA => 4.5,
+            Self::AALargeText => 3.0,
+            Self::AAA => 7.0,
+            Self::AAALargeText => 4.5,
+        }
+    }
+}
+
+/// Contrast.
+///
+/// Backgrounds that are not opaque are drawn on white, foregrounds that are not opaque on their background.
+/// All of these return `None` for colors that can only be resolved by the browser.
+impl Color {
+    /// The relative luminance (0 - 1) of the color, ignoring alpha
+    pub fn relative_luminance(&self) -> Option<f64> {
+        self.srgb_channels().map(|(rgb, _)| luminance(super::clamp(rgb)))
+    }
+
+    /// The contrast ratio (1 - 21) of the color as the foreground of `background`
+    pub fn contrast_ratio(&self, background: &Color) -> Option<f64> {
+        let (background, background_alpha) = background.srgb_channels()?;
+        let background = blend(super::clamp(background), background_alpha, [1.0; 3]);
+        let (foreground, alpha) = self.srgb_channels()?;
+        let foreground = blend(super::clamp(foreground), alpha, background);
+        let (a, b) = (luminance(foreground), luminance(background));
+        Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Whether the color as the foreground of `background` meets `level`
+    pub fn meets(&self, background: &Color, level: WcagLevel) -> Option<bool> {
+        self.contrast_ratio(background).map(|ratio| ratio >= level.min_ratio())
+    }
+
+    /// The color closest to this one that meets `level` as the foreground of `background`.
+    ///
+    /// Hue and chroma are kept, only the Oklab lightness is moved towards black or white, whichever needs the smaller change.
+    /// The result is in sRGB, or `None` if not even black or white meet the level.
+    pub fn accessible_foreground(&self, background: &Color, level: WcagLevel) -> Option<Color> {
+        if self.meets(background, level)? {
+            return self.to_srgb();
+        }
+        let (l, c, h, alpha) = match self.to_oklch()? {
+            Color::OKLCH(l, c, h, alpha) => (l, c, h, alpha),
+            _ => unreachable!(),
+        };
+        let candidate = |lightness: f64| Color::OKLCH(lightness, c, h, alpha).to_srgb();
+        let passes = |lightness: f64| candidate(lightness).and_then(|x| x.meets(background, level)).unwrap_or(false);
+
+        [0.0, 1.0].iter()
+            .filter(|&&end| passes(end))
+            .map(|&end| {
+                // the contrast grows monotonically towards the end, so the closest passing lightness can be bisected
+                let (mut failing, mut passing) = (l, end);
+                for _ in 0..32 {
+                    let middle = (failing + passing) / 2.0;
+                    if passes(middle) {
+                        passing = middle;
+                    } else {
+                        failing = middle;
+                    }
+                }
+                passing
+            })
+            .min_by(|a, b| (a - l).abs().total_cmp(&(b - l).abs()))
+            .and_then(candidate)
+    }
+}
+
+fn luminance(rgb: convert::Channels) -> f64 {
+    let [r, g, b] = convert::srgb_to_linear(rgb);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Draws `color` with `alpha` on an opaque `background`
+fn blend(color: convert::Channels, alpha: f64, background: convert::Channels) -> convert::Channels {
+    [0, 1, 2].map(|i| color[i] * alpha + background[i] * (1.0 - alpha))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{color::NamedColor, IntoCss};
+
+    use super::*;
+
+    fn color(name: NamedColor) -> Color {
+        name.into()
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let (black, white) = (color(NamedColor::Black), color(NamedColor::White));
+        assert_eq!(black.contrast_ratio(&white), Some(21.0));
+        assert_eq!(white.contrast_ratio(&black), Some(21.0));
+        assert_eq!(white.contrast_ratio(&white), Some(1.0));
+        let gray = Color::RGB(118, 118, 118);
+        assert!((gray.contrast_ratio(&white).unwrap() - 4.54).abs() < 0.01);
+        assert_eq!(gray.meets(&white, WcagLevel::AA), Some(true));
+        assert_eq!(gray.meets(&white, WcagLevel::AAA), Some(false));
+        assert!(Color::CurrentColor.contrast_ratio(&white).is_none());
+    }
+
+    #[test]
+    fn transparent_colors_are_blended() {
+        let white = color(NamedColor::White);
+        // a transparent foreground disappears in its background
+        assert_eq!(Color::RGBA(0, 0, 0, 0.0).contrast_ratio(&white), Some(1.0));
+        // a transparent background is drawn on white
+        assert_eq!(color(NamedColor::Black).contrast_ratio(&Color::Transparent), Some(21.0));
+    }
+
+    #[test]
+    fn accessible_foreground() {
+        let white = color(NamedColor::White);
+        let light = Color::RGB(150, 170, 255);
+        let fixed = light.accessible_foreground(&white, WcagLevel::AA).unwrap();
+        let ratio = fixed.contrast_ratio(&white).unwrap();
+        assert!((4.5..4.6).contains(&ratio), "{}", ratio);
+        // colors that already meet the level are kept
+        let navy = color(NamedColor::Navy);
+        assert_eq!(navy.accessible_foreground(&white, WcagLevel::AAA).unwrap().into_css(), "rgb(0, 0, 128)");
+        // on a mid gray not even black or white reach 7:1
+        assert!(light.accessible_foreground(&Color::RGB(119, 119, 119), WcagLevel::AAA).is_none());
+    }
+}
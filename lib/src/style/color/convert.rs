@@ -0,0 +1,340 @@
+//! Conversions between color spaces, following the sample code of the CSS Color 4 specification.
+//! 
+//! Colors are passed around as three channels, XYZ with a D65 white point is used as the connection space.
+
+use super::ColorSpace;
+
+pub(super) type Channels = [f64; 3];
+
+type Matrix = [[f64; 3]; 3];
+
+fn multiply(m: &Matrix, [a, b, c]: Channels) -> Channels {
+    [
+        m[0][0] * a + m[0][1] * b + m[0][2] * c,
+        m[1][0] * a + m[1][1] * b + m[1][2] * c,
+        m[2][0] * a + m[2][1] * b + m[2][2] * c,
+    ]
+}
+
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496606],
+];
+
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+const LINEAR_P3_TO_XYZ: Matrix = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const XYZ_TO_LINEAR_P3: Matrix = [
+    [2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+    [-0.8294889695615747, 1.7626640603183463, 0.023624685841943577],
+    [0.03584583024378447, -0.07617238926804182, 0.9568845240076872],
+];
+
+const LINEAR_A98_TO_XYZ: Matrix = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+const XYZ_TO_LINEAR_A98: Matrix = [
+    [2.0415879038107465, -0.5650069742788596, -0.34473135077832956],
+    [-0.9692436362808795, 1.8759675015077202, 0.04155505740717557],
+    [0.013444280632031142, -0.11836239223101838, 1.0151749943912054],
+];
+
+const LINEAR_REC2020_TO_XYZ: Matrix = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+const XYZ_TO_LINEAR_REC2020: Matrix = [
+    [1.7166511879712674, -0.35567078377639233, -0.25336628137365974],
+    [-0.6666843518324892, 1.6164812366349395, 0.01576854581391113],
+    [0.017639857445310783, -0.042770613257808524, 0.9421031212354738],
+];
+
+/// ProPhoto RGB uses a D50 white point
+const LINEAR_PROPHOTO_TO_XYZ_D50: Matrix = [
+    [0.7977604896723027, 0.13518583717574031, 0.0313493495815248],
+    [0.2880711282292934, 0.7118432178101014, 0.00008565396060525902],
+    [0.0, 0.0, 0.8251046025104601],
+];
+
+const XYZ_D50_TO_LINEAR_PROPHOTO: Matrix = [
+    [1.3457989731028281, -0.25558010007997534, -0.05110628506753401],
+    [-0.5446224939028347, 1.5082327413132781, 0.02053603239147973],
+    [0.0, 0.0, 1.2119675456389454],
+];
+
+/// Bradford chromatic adaptation
+const D65_TO_D50: Matrix = [
+    [1.0479298208405488, 0.022946793341019088, -0.05019222954313557],
+    [0.029627815688159344, 0.990434484573249, -0.01707382502938514],
+    [-0.009243058152591178, 0.015055144896577895, 0.7518742899580008],
+];
+
+const D50_TO_D65: Matrix = [
+    [0.9554734527042182, -0.023098536874261423, 0.0632593086610217],
+    [-0.028369706963208136, 1.0099954580058226, 0.021041398966943008],
+    [0.012314001688319899, -0.020507696433477912, 1.3303659366080753],
+];
+
+const XYZ_TO_LMS: Matrix = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.977998532431168, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+const LMS_TO_XYZ: Matrix = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+/// Reference white of CIE Lab
+const D50_WHITE: Channels = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+fn map(channels: Channels, f: impl Fn(f64) -> f64) -> Channels {
+    [f(channels[0]), f(channels[1]), f(channels[2])]
+}
+
+/// Applies `f` to the magnitude of `value` and keeps its sign, transfer functions are extended to negative values this way
+fn signed(value: f64, f: impl Fn(f64) -> f64) -> f64 {
+    value.signum() * f(value.abs())
+}
+
+pub(super) fn srgb_to_linear(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) }))
+}
+
+pub(super) fn linear_to_srgb(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| if v > 0.0031308 { 1.055 * v.powf(1.0 / 2.4) - 0.055 } else { 12.92 * v }))
+}
+
+fn a98_to_linear(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| v.powf(563.0 / 256.0)))
+}
+
+fn linear_to_a98(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| v.powf(256.0 / 563.0)))
+}
+
+fn prophoto_to_linear(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| if v <= 16.0 / 512.0 { v / 16.0 } else { v.powf(1.8) }))
+}
+
+fn linear_to_prophoto(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| if v >= 1.0 / 512.0 { v.powf(1.0 / 1.8) } else { 16.0 * v }))
+}
+
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+fn rec2020_to_linear(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| {
+        if v < REC2020_BETA * 4.5 { v / 4.5 } else { ((v + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45) }
+    }))
+}
+
+fn linear_to_rec2020(rgb: Channels) -> Channels {
+    map(rgb, |v| signed(v, |v| {
+        if v > REC2020_BETA { REC2020_ALPHA * v.powf(0.45) - (REC2020_ALPHA - 1.0) } else { 4.5 * v }
+    }))
+}
+
+/// Converts the channels of a predefined color space to XYZ (D65)
+pub(super) fn space_to_xyz(space: ColorSpace, channels: Channels) -> Channels {
+    match space {
+        ColorSpace::Srgb => multiply(&LINEAR_SRGB_TO_XYZ, srgb_to_linear(channels)),
+        ColorSpace::SrgbLinear => multiply(&LINEAR_SRGB_TO_XYZ, channels),
+        ColorSpace::DisplayP3 => multiply(&LINEAR_P3_TO_XYZ, srgb_to_linear(channels)),
+        ColorSpace::A98Rgb => multiply(&LINEAR_A98_TO_XYZ, a98_to_linear(channels)),
+        ColorSpace::ProphotoRgb => multiply(&D50_TO_D65, multiply(&LINEAR_PROPHOTO_TO_XYZ_D50, prophoto_to_linear(channels))),
+        ColorSpace::Rec2020 => multiply(&LINEAR_REC2020_TO_XYZ, rec2020_to_linear(channels)),
+        ColorSpace::XyzD50 => multiply(&D50_TO_D65, channels),
+        ColorSpace::XyzD65 => channels,
+    }
+}
+
+/// Converts XYZ (D65) to the channels of a predefined color space
+pub(super) fn xyz_to_space(space: ColorSpace, xyz: Channels) -> Channels {
+    match space {
+        ColorSpace::Srgb => linear_to_srgb(multiply(&XYZ_TO_LINEAR_SRGB, xyz)),
+        ColorSpace::SrgbLinear => multiply(&XYZ_TO_LINEAR_SRGB, xyz),
+        ColorSpace::DisplayP3 => linear_to_srgb(multiply(&XYZ_TO_LINEAR_P3, xyz)),
+        ColorSpace::A98Rgb => linear_to_a98(multiply(&XYZ_TO_LINEAR_A98, xyz)),
+        ColorSpace::ProphotoRgb => linear_to_prophoto(multiply(&XYZ_D50_TO_LINEAR_PROPHOTO, multiply(&D65_TO_D50, xyz))),
+        ColorSpace::Rec2020 => linear_to_rec2020(multiply(&XYZ_TO_LINEAR_REC2020, xyz)),
+        ColorSpace::XyzD50 => multiply(&D65_TO_D50, xyz),
+        ColorSpace::XyzD65 => xyz,
+    }
+}
+
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+pub(super) fn xyz_to_lab(xyz: Channels) -> Channels {
+    let xyz = multiply(&D65_TO_D50, xyz);
+    let [x, y, z] = [0, 1, 2].map(|i| {
+        let v = xyz[i] / D50_WHITE[i];
+        if v > LAB_EPSILON { v.cbrt() } else { (LAB_KAPPA * v + 16.0) / 116.0 }
+    });
+    [116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z)]
+}
+
+pub(super) fn lab_to_xyz([l, a, b]: Channels) -> Channels {
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let x = if fx.powi(3) > LAB_EPSILON { fx.powi(3) } else { (116.0 * fx - 16.0) / LAB_KAPPA };
+    let y = if l > LAB_KAPPA * LAB_EPSILON { fy.powi(3) } else { l / LAB_KAPPA };
+    let z = if fz.powi(3) > LAB_EPSILON { fz.powi(3) } else { (116.0 * fz - 16.0) / LAB_KAPPA };
+    multiply(&D50_TO_D65, [x * D50_WHITE[0], y * D50_WHITE[1], z * D50_WHITE[2]])
+}
+
+pub(super) fn xyz_to_oklab(xyz: Channels) -> Channels {
+    multiply(&LMS_TO_OKLAB, map(multiply(&XYZ_TO_LMS, xyz), f64::cbrt))
+}
+
+pub(super) fn oklab_to_xyz(oklab: Channels) -> Channels {
+    multiply(&LMS_TO_XYZ, map(multiply(&OKLAB_TO_LMS, oklab), |v| v.powi(3)))
+}
+
+/// Converts the rectangular a and b axis of Lab or Oklab to chroma and hue
+pub(super) fn lab_to_lch([l, a, b]: Channels) -> Channels {
+    let hue = b.atan2(a).to_degrees();
+    [l, a.hypot(b), if hue < 0.0 { hue + 360.0 } else { hue }]
+}
+
+pub(super) fn lch_to_lab([l, c, h]: Channels) -> Channels {
+    let h = h.to_radians();
+    [l, c * h.cos(), c * h.sin()]
+}
+
+/// sRGB channels (0 - 1) to hue, saturation and lightness (0 - 100)
+pub(super) fn srgb_to_hsl([r, g, b]: Channels) -> Channels {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+    if delta == 0.0 {
+        return [0.0, 0.0, lightness * 100.0];
+    }
+    let saturation = if lightness == 0.0 || lightness == 1.0 {
+        0.0
+    } else {
+        (max - lightness) / lightness.min(1.0 - lightness)
+    };
+    let hue = if max == r {
+        (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    [hue * 60.0, saturation * 100.0, lightness * 100.0]
+}
+
+/// Hue, saturation and lightness (0 - 100) to sRGB channels (0 - 1)
+pub(super) fn hsl_to_srgb([hue, saturation, lightness]: Channels) -> Channels {
+    let hue = hue.rem_euclid(360.0);
+    let saturation = saturation / 100.0;
+    let lightness = lightness / 100.0;
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+/// sRGB channels (0 - 1) to hue, whiteness and blackness (0 - 100)
+pub(super) fn srgb_to_hwb(rgb: Channels) -> Channels {
+    let [hue, ..] = srgb_to_hsl(rgb);
+    let white = rgb[0].min(rgb[1]).min(rgb[2]);
+    let black = 1.0 - rgb[0].max(rgb[1]).max(rgb[2]);
+    [hue, white * 100.0, black * 100.0]
+}
+
+/// Hue, whiteness and blackness (0 - 100) to sRGB channels (0 - 1)
+pub(super) fn hwb_to_srgb([hue, white, black]: Channels) -> Channels {
+    let white = white / 100.0;
+    let black = black / 100.0;
+    if white + black >= 1.0 {
+        let gray = white / (white + black);
+        return [gray; 3];
+    }
+    map(hsl_to_srgb([hue, 100.0, 50.0]), |v| v * (1.0 - white - black) + white)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Channels, b: Channels, tolerance: f64) {
+        assert!((0..3).all(|i| (a[i] - b[i]).abs() < tolerance), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn srgb_white_is_d65() {
+        assert_close(space_to_xyz(ColorSpace::Srgb, [1.0; 3]), [0.9505, 1.0, 1.089], 1e-3);
+    }
+
+    #[test]
+    fn spaces_round_trip() {
+        let spaces = [
+            ColorSpace::Srgb, ColorSpace::SrgbLinear, ColorSpace::DisplayP3, ColorSpace::A98Rgb,
+            ColorSpace::ProphotoRgb, ColorSpace::Rec2020, ColorSpace::XyzD50, ColorSpace::XyzD65,
+        ];
+        let color = [0.2, 0.5, 0.8];
+        for space in spaces {
+            assert_close(xyz_to_space(space, space_to_xyz(space, color)), color, 1e-6);
+        }
+    }
+
+    #[test]
+    fn lab_and_oklab() {
+        let white = space_to_xyz(ColorSpace::Srgb, [1.0; 3]);
+        assert_close(xyz_to_lab(white), [100.0, 0.0, 0.0], 1e-3);
+        assert_close(xyz_to_oklab(white), [1.0, 0.0, 0.0], 1e-3);
+        let red = space_to_xyz(ColorSpace::Srgb, [1.0, 0.0, 0.0]);
+        assert_close(lab_to_lch(xyz_to_oklab(red)), [0.628, 0.2577, 29.234], 1e-3);
+        assert_close(lab_to_xyz(xyz_to_lab(red)), red, 1e-6);
+        assert_close(oklab_to_xyz(xyz_to_oklab(red)), red, 1e-6);
+        assert_close(lch_to_lab(lab_to_lch([50.0, 20.0, -30.0])), [50.0, 20.0, -30.0], 1e-9);
+    }
+
+    #[test]
+    fn hsl_and_hwb() {
+        assert_close(srgb_to_hsl([1.0, 0.5, 0.0]), [30.0, 100.0, 50.0], 1e-9);
+        assert_close(hsl_to_srgb([30.0, 100.0, 50.0]), [1.0, 0.5, 0.0], 1e-9);
+        assert_close(hsl_to_srgb([-330.0, 100.0, 50.0]), [1.0, 0.5, 0.0], 1e-9);
+        assert_close(srgb_to_hwb([0.2, 0.4, 0.8]), [220.0, 20.0, 20.0], 1e-9);
+        assert_close(hwb_to_srgb([220.0, 20.0, 20.0]), [0.2, 0.4, 0.8], 1e-9);
+        // whiteness and blackness above 100% together are a gray
+        assert_close(hwb_to_srgb([0.0, 60.0, 60.0]), [0.5; 3], 1e-9);
+    }
+}
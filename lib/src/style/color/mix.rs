@@ -0,0 +1,234 @@
+//! `color-mix()` (https://www.w3.org/TR/css-color-5/#color-mix).
+
+use crate::{css_enum, style::IntoCss};
+
+use super::{convert::{self, Channels}, Color, ColorSpace};
+
+css_enum!(InterpolationSpace;
+    Srgb | SrgbLinear "srgb-linear" | DisplayP3 "display-p3" | A98Rgb "a98-rgb" | ProphotoRgb "prophoto-rgb" | Rec2020 |
+    Lab | Oklab | XyzD50 "xyz-d50" | XyzD65 "xyz-d65" | Hsl | Hwb | Lch | Oklch
+);
+
+css_enum!(HueInterpolation; Shorter | Longer | Increasing | Decreasing);
+
+/// `color-mix(in <space>, <first> <percentage>?, <second> <percentage>?)`
+#[derive(Clone, Debug)]
+pub struct ColorMix {
+    pub space: InterpolationSpace,
+    /// How the hue of the polar spaces is interpolated, `shorter` if `None`
+    pub hue: Option<HueInterpolation>,
+    pub first: Color,
+    pub first_percentage: Option<f64>,
+    pub second: Color,
+    pub second_percentage: Option<f64>,
+}
+
+impl ColorMix {
+    pub fn new(
+        space: InterpolationSpace,
+        first: impl Into<Color>, first_percentage: Option<f64>,
+        second: impl Into<Color>, second_percentage: Option<f64>,
+    ) -> Self {
+        Self { space, hue: None, first: first.into(), first_percentage, second: second.into(), second_percentage }
+    }
+
+    /// Computes the mix like the browser would, the result is a color in the interpolation space.
+    ///
+    /// `None` if one of the colors can only be resolved by the browser or both percentages are zero.
+    pub fn resolve(&self) -> Option<Color> {
+        let (p1, p2) = match (self.first_percentage, self.second_percentage) {
+            (None, None) => (50.0, 50.0),
+            (Some(p1), None) => (p1, 100.0 - p1),
+            (None, Some(p2)) => (100.0 - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        let sum = p1 + p2;
+        if sum <= 0.0 {
+            return None;
+        }
+        // percentages that add up to less than 100% make the result transparent
+        let alpha_multiplier = sum.min(100.0) / 100.0;
+        let weight = p2 / sum;
+
+        let (mut a, a_alpha) = to_space(self.space, &self.first)?;
+        let (mut b, b_alpha) = to_space(self.space, &self.second)?;
+        let alpha = a_alpha * (1.0 - weight) + b_alpha * weight;
+
+        if let Some(hue) = hue_index(self.space) {
+            // a hue without meaning (e.g. of a gray) takes the hue of the other color
+            if powerless(self.space, a) {
+                a[hue] = b[hue];
+            } else if powerless(self.space, b) {
+                b[hue] = a[hue];
+            }
+            let (h1, h2) = fix_hues(a[hue], b[hue], self.hue.unwrap_or(HueInterpolation::Shorter));
+            a[hue] = h1;
+            b[hue] = h2;
+        }
+
+        let mixed = [0, 1, 2].map(|i| {
+            if Some(i) == hue_index(self.space) {
+                (a[i] * (1.0 - weight) + b[i] * weight).rem_euclid(360.0)
+            } else if alpha == 0.0 {
+                0.0
+            } else {
+                (a[i] * a_alpha * (1.0 - weight) + b[i] * b_alpha * weight) / alpha
+            }
+        });
+        Some(from_space(self.space, mixed, alpha * alpha_multiplier))
+    }
+}
+
+impl IntoCss for ColorMix {
+    fn into_css(self) -> String {
+        let space = match self.hue {
+            Some(hue) => format!("{} {} hue", self.space.into_css(), hue.into_css()),
+            None => self.space.into_css(),
+        };
+        let color = |color: Color, percentage: Option<f64>| match percentage {
+            Some(percentage) => format!("{} {percentage}%", color.into_css()),
+            None => color.into_css(),
+        };
+        format!("color-mix(in {space}, {}, {})", color(self.first, self.first_percentage), color(self.second, self.second_percentage))
+    }
+}
+
+impl From<ColorMix> for Color {
+    fn from(mix: ColorMix) -> Self {
+        Self::Mix(Box::new(mix))
+    }
+}
+
+/// The channels of `color` in the interpolation space and its alpha
+fn to_space(space: InterpolationSpace, color: &Color) -> Option<(Channels, f64)> {
+    let (xyz, alpha) = color.xyz()?;
+    let srgb = || super::clamp(convert::xyz_to_space(ColorSpace::Srgb, xyz));
+    let channels = match rectangular_space(space) {
+        Some(space) => convert::xyz_to_space(space, xyz),
+        None => match space {
+            InterpolationSpace::Lab => convert::xyz_to_lab(xyz),
+            InterpolationSpace::Oklab => convert::xyz_to_oklab(xyz),
+            InterpolationSpace::Lch => convert::lab_to_lch(convert::xyz_to_lab(xyz)),
+            InterpolationSpace::Oklch => convert::lab_to_lch(convert::xyz_to_oklab(xyz)),
+            InterpolationSpace::Hsl => convert::srgb_to_hsl(srgb()),
+            _ => convert::srgb_to_hwb(srgb()),
+        },
+    };
+    Some((channels, alpha))
+}
+
+fn from_space(space: InterpolationSpace, channels: Channels, alpha: f64) -> Color {
+    let mut channels = channels.map(|v| super::round(v, 4));
+    if let Some(hue) = hue_index(space) {
+        // a hue just below 360 can be rounded up to it
+        channels[hue] %= 360.0;
+    }
+    let [x, y, z] = channels;
+    let alpha = super::optional_alpha(alpha);
+    match rectangular_space(space) {
+        Some(space) => Color::Space(space, x, y, z, alpha),
+        None => match space {
+            InterpolationSpace::Lab => Color::LAB(x, y, z, alpha),
+            InterpolationSpace::Oklab => Color::OKLAB(x, y, z, alpha),
+            InterpolationSpace::Lch => Color::LCH(x, y, z, alpha),
+            InterpolationSpace::Oklch => Color::OKLCH(x, y, z, alpha),
+            InterpolationSpace::Hsl => super::from_hsl(channels, alpha.unwrap_or(1.0)),
+            _ => Color::HWB(x, y, z, alpha),
+        },
+    }
+}
+
+/// The `color()` space of the rectangular rgb and xyz interpolation spaces
+fn rectangular_space(space: InterpolationSpace) -> Option<ColorSpace> {
+    match space {
+        InterpolationSpace::Srgb => Some(ColorSpace::Srgb),
+        InterpolationSpace::SrgbLinear => Some(ColorSpace::SrgbLinear),
+        InterpolationSpace::DisplayP3 => Some(ColorSpace::DisplayP3),
+        InterpolationSpace::A98Rgb => Some(ColorSpace::A98Rgb),
+        InterpolationSpace::ProphotoRgb => Some(ColorSpace::ProphotoRgb),
+        InterpolationSpace::Rec2020 => Some(ColorSpace::Rec2020),
+        InterpolationSpace::XyzD50 => Some(ColorSpace::XyzD50),
+        InterpolationSpace::XyzD65 => Some(ColorSpace::XyzD65),
+        _ => None,
+    }
+}
+
+fn hue_index(space: InterpolationSpace) -> Option<usize> {
+    match space {
+        InterpolationSpace::Hsl | InterpolationSpace::Hwb => Some(0),
+        InterpolationSpace::Lch | InterpolationSpace::Oklch => Some(2),
+        _ => None,
+    }
+}
+
+/// Whether the hue has no meaning because the color is achromatic
+fn powerless(space: InterpolationSpace, [x, y, z]: Channels) -> bool {
+    const EPSILON: f64 = 1e-4;
+    match space {
+        InterpolationSpace::Hsl => y < EPSILON || !(EPSILON..=100.0 - EPSILON).contains(&z),
+        InterpolationSpace::Hwb => y + z >= 100.0 - EPSILON,
+        InterpolationSpace::Lch => y < 1e-2,
+        _ => x < EPSILON || y < EPSILON,
+    }
+}
+
+/// Adjusts two hues in degrees for the hue interpolation method
+fn fix_hues(h1: f64, h2: f64, method: HueInterpolation) -> (f64, f64) {
+    let (h1, mut h2) = (h1.rem_euclid(360.0), h2.rem_euclid(360.0));
+    let difference = h2 - h1;
+    match method {
+        HueInterpolation::Shorter if difference > 180.0 => h2 -= 360.0,
+        HueInterpolation::Shorter if difference < -180.0 => h2 += 360.0,
+        HueInterpolation::Longer if difference > 0.0 && difference < 180.0 => h2 -= 360.0,
+        HueInterpolation::Longer if difference < 0.0 && difference > -180.0 => h2 += 360.0,
+        HueInterpolation::Increasing if difference < 0.0 => h2 += 360.0,
+        HueInterpolation::Decreasing if difference > 0.0 => h2 -= 360.0,
+        _ => {},
+    }
+    (h1, h2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{color::{ColorFormat, NamedColor}, variable::var};
+
+    use super::*;
+
+    fn css(color: Color) -> String {
+        color.to_css_with(ColorFormat::Functional)
+    }
+
+    #[test]
+    fn serialization() {
+        let mix = ColorMix::new(InterpolationSpace::Oklch, var::<Color>("brand"), Some(70.0), NamedColor::White, None);
+        assert_eq!(css(mix.into()), "color-mix(in oklch, var(--brand) 70%, white)");
+        let mut mix = ColorMix::new(InterpolationSpace::Hsl, NamedColor::Red, None, NamedColor::Blue, Some(25.0));
+        mix.hue = Some(HueInterpolation::Longer);
+        assert_eq!(css(mix.into()), "color-mix(in hsl longer hue, red, blue 25%)");
+    }
+
+    #[test]
+    fn resolve() {
+        let srgb = ColorMix::new(InterpolationSpace::Srgb, NamedColor::Red, None, NamedColor::Blue, None);
+        assert_eq!(css(srgb.resolve().unwrap()), "color(srgb 0.5 0 0.5)");
+        // percentages below 100% in total make the mix transparent
+        let faded = ColorMix::new(InterpolationSpace::Srgb, NamedColor::Black, Some(25.0), NamedColor::White, Some(25.0));
+        assert_eq!(css(faded.resolve().unwrap()), "color(srgb 0.5 0.5 0.5 / 0.5)");
+        assert!(ColorMix::new(InterpolationSpace::Srgb, NamedColor::Red, Some(0.0), NamedColor::Blue, Some(0.0)).resolve().is_none());
+        assert!(ColorMix::new(InterpolationSpace::Srgb, Color::CurrentColor, None, NamedColor::Blue, None).resolve().is_none());
+    }
+
+    #[test]
+    fn hue_interpolation() {
+        let hsl = |hue: Option<HueInterpolation>| {
+            let mut mix = ColorMix::new(InterpolationSpace::Hsl, Color::hsl(30.0, 100.0, 50.0), None, Color::hsl(330.0, 100.0, 50.0), None);
+            mix.hue = hue;
+            css(mix.resolve().unwrap())
+        };
+        assert_eq!(hsl(None), "hsl(0, 100%, 50%)");
+        assert_eq!(hsl(Some(HueInterpolation::Longer)), "hsl(180, 100%, 50%)");
+        // the hue of a gray is powerless and takes the hue of the other color
+        let gray = ColorMix::new(InterpolationSpace::Hsl, Color::hsl(0.0, 0.0, 50.0), None, Color::hsl(240.0, 100.0, 50.0), None);
+        assert_eq!(css(gray.resolve().unwrap()), "hsl(240, 50%, 50%)");
+    }
+}
@@ -0,0 +1,307 @@
+//! Parsing of hex colors, the color functions, named colors and the color keywords (https://www.w3.org/TR/css-color-4/).
+
+use std::{convert::TryFrom, fmt::Display, str::FromStr};
+
+use crate::style::{quantities::Angle, IntoCss};
+
+use super::{Color, ColorSpace, NamedColor, SystemColor};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseColorError {
+    /// The input is empty
+    Empty,
+    /// A `#` color that does not have 3, 4, 6 or 8 hex digits
+    InvalidHex(String),
+    /// An unknown named color or keyword
+    UnknownName(String),
+    /// An unknown color function
+    UnknownFunction(String),
+    /// A color space in `color()` that is not one of the predefined ones
+    UnknownColorSpace(String),
+    /// A component of a color function that is not valid there
+    InvalidComponent(String),
+    /// A color function with the wrong number of components
+    ComponentCount { function: String, count: usize },
+    /// A color function without the closing `)`
+    Unclosed,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty color"),
+            Self::InvalidHex(hex) => write!(f, "invalid hex color `{hex}`"),
+            Self::UnknownName(name) => write!(f, "unknown color `{name}`"),
+            Self::UnknownFunction(function) => write!(f, "unknown color function `{function}()`"),
+            Self::UnknownColorSpace(space) => write!(f, "unknown color space `{space}`"),
+            Self::InvalidComponent(component) => write!(f, "invalid color component `{component}`"),
+            Self::ComponentCount { function, count } => write!(f, "`{function}()` does not take {count} components"),
+            Self::Unclosed => write!(f, "missing `)`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            Err(ParseColorError::Empty)
+        } else if let Some(hex) = s.strip_prefix('#') {
+            parse_hex(hex).ok_or_else(|| ParseColorError::InvalidHex(s.to_string()))
+        } else if let Some((function, arguments)) = s.split_once('(') {
+            let arguments = arguments.strip_suffix(')').ok_or(ParseColorError::Unclosed)?;
+            parse_function(&function.trim_end().to_ascii_lowercase(), arguments)
+        } else if s.eq_ignore_ascii_case("transparent") {
+            Ok(Color::Transparent)
+        } else if s.eq_ignore_ascii_case("currentcolor") {
+            Ok(Color::CurrentColor)
+        } else if let Some(system) = SYSTEM_COLORS.iter().find(|system| system.into_css().eq_ignore_ascii_case(s)) {
+            Ok(Color::System(*system))
+        } else {
+            NamedColor::from_name(s).map(Color::Named).ok_or_else(|| ParseColorError::UnknownName(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ParseColorError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok();
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let alpha = |a: u8| a as f64 / 255.0;
+    match hex.len() {
+        3 => Some(Color::RGB(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17)),
+        4 => Some(Color::RGBA(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17, alpha(digit(3)? * 17))),
+        6 => Some(Color::RGB(pair(0)?, pair(2)?, pair(4)?)),
+        8 => Some(Color::RGBA(pair(0)?, pair(2)?, pair(4)?, alpha(pair(6)?))),
+        _ => None,
+    }
+}
+
+/// A number or percentage component, `none` is zero
+#[derive(Clone, Copy)]
+enum Component {
+    Number(f64),
+    Percent(f64),
+}
+
+impl Component {
+    /// The value with `100%` mapped to `reference`
+    fn scale(self, reference: f64) -> f64 {
+        match self {
+            Self::Number(x) => x,
+            Self::Percent(x) => x * reference / 100.0,
+        }
+    }
+}
+
+fn parse_component(s: &str) -> Result<Component, ParseColorError> {
+    let invalid = || ParseColorError::InvalidComponent(s.to_string());
+    if s.eq_ignore_ascii_case("none") {
+        Ok(Component::Number(0.0))
+    } else if let Some(percent) = s.strip_suffix('%') {
+        parse_number(percent).map(Component::Percent).ok_or_else(invalid)
+    } else {
+        parse_number(s).map(Component::Number).ok_or_else(invalid)
+    }
+}
+
+/// A css `<number>`: an optional sign, digits with an optional fraction (or only a fraction) and an optional exponent.
+///
+/// Unlike `f64::from_str` this rejects `inf`, `nan` and numbers like `1.` that are not valid css.
+fn parse_number(s: &str) -> Option<f64> {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(e) => (&unsigned[..e], Some(&unsigned[e + 1..])),
+        None => (unsigned, None),
+    };
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_digit());
+    let valid_mantissa = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer.is_empty() || digits(integer)) && digits(fraction),
+        None => digits(mantissa),
+    };
+    let valid_exponent = match exponent {
+        Some(exponent) => digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)),
+        None => true,
+    };
+    if !valid_mantissa || !valid_exponent {
+        return None;
+    }
+    s.parse().ok().filter(|number: &f64| number.is_finite())
+}
+
+type AngleUnit = fn(f64) -> Angle;
+
+const ANGLE_UNITS: [(&str, AngleUnit); 4] = [("deg", Angle::Deg), ("grad", Angle::Grad), ("rad", Angle::Rad), ("turn", Angle::Turn)];
+
+/// A hue in degrees, a number or an angle
+fn parse_hue(s: &str) -> Result<f64, ParseColorError> {
+    let lower = s.to_ascii_lowercase();
+    for (unit, angle) in ANGLE_UNITS {
+        if let Some(value) = lower.strip_suffix(unit).and_then(parse_number) {
+            return Ok(angle(value).to_deg());
+        }
+    }
+    match parse_component(s)? {
+        Component::Number(x) => Ok(x),
+        Component::Percent(_) => Err(ParseColorError::InvalidComponent(s.to_string())),
+    }
+}
+
+/// Alpha as a number or percentage, clamped to 0 - 1
+fn parse_alpha(s: &str) -> Result<f64, ParseColorError> {
+    Ok(parse_component(s)?.scale(1.0).clamp(0.0, 1.0))
+}
+
+/// Splits the arguments of a color function into the components and the optional alpha,
+/// both the legacy comma separated and the modern space separated syntax are accepted.
+fn split_arguments(arguments: &str) -> (Vec<&str>, Option<&str>) {
+    if arguments.contains(',') {
+        let mut components: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let alpha = if components.len() == 4 { components.pop() } else { None };
+        (components, alpha)
+    } else {
+        let (components, alpha) = match arguments.split_once('/') {
+            Some((components, alpha)) => (components, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        (components.split_whitespace().collect(), alpha)
+    }
+}
+
+const FUNCTIONS: [&str; 10] = ["rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color"];
+
+fn parse_function(function: &str, arguments: &str) -> Result<Color, ParseColorError> {
+    if !FUNCTIONS.contains(&function) {
+        return Err(ParseColorError::UnknownFunction(function.to_string()));
+    }
+    let (mut components, alpha) = split_arguments(arguments);
+    let space = if function == "color" && !components.is_empty() {
+        let name = components.remove(0);
+        let space = PREDEFINED_SPACES.iter().copied().find(|space| space.into_css().eq_ignore_ascii_case(name));
+        Some(space.ok_or_else(|| ParseColorError::UnknownColorSpace(name.to_string()))?)
+    } else {
+        None
+    };
+    if components.len() != 3 {
+        return Err(ParseColorError::ComponentCount { function: function.to_string(), count: components.len() + alpha.iter().count() });
+    }
+    let component = |i: usize| parse_component(components[i]);
+    let alpha_or_opaque = || alpha.map(parse_alpha).transpose();
+
+    match function {
+        "rgb" | "rgba" => {
+            let channel = |i: usize| component(i).map(|c| c.scale(255.0).round().clamp(0.0, 255.0) as u8);
+            let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+            match alpha {
+                Some(alpha) => Ok(Color::RGBA(r, g, b, parse_alpha(alpha)?)),
+                None => Ok(Color::RGB(r, g, b)),
+            }
+        },
+        "hsl" | "hsla" => {
+            let (h, s, l) = (parse_hue(components[0])?, component(1)?.scale(100.0), component(2)?.scale(100.0));
+            Ok(Color::HSL(h, s, l, alpha.map(parse_alpha).transpose()?))
+        },
+        "hwb" => Ok(Color::HWB(parse_hue(components[0])?, component(1)?.scale(100.0), component(2)?.scale(100.0), alpha_or_opaque()?)),
+        "lab" => Ok(Color::LAB(component(0)?.scale(100.0), component(1)?.scale(125.0), component(2)?.scale(125.0), alpha_or_opaque()?)),
+        "lch" => Ok(Color::LCH(component(0)?.scale(100.0), component(1)?.scale(150.0), parse_hue(components[2])?, alpha_or_opaque()?)),
+        "oklab" => Ok(Color::OKLAB(component(0)?.scale(1.0), component(1)?.scale(0.4), component(2)?.scale(0.4), alpha_or_opaque()?)),
+        "oklch" => Ok(Color::OKLCH(component(0)?.scale(1.0), component(1)?.scale(0.4), parse_hue(components[2])?, alpha_or_opaque()?)),
+        _ => {
+            let space = space.unwrap_or(ColorSpace::Srgb);
+            Ok(Color::Space(space, component(0)?.scale(1.0), component(1)?.scale(1.0), component(2)?.scale(1.0), alpha_or_opaque()?))
+        },
+    }
+}
+
+const PREDEFINED_SPACES: [ColorSpace; 8] = [
+    ColorSpace::Srgb, ColorSpace::SrgbLinear, ColorSpace::DisplayP3, ColorSpace::A98Rgb,
+    ColorSpace::ProphotoRgb, ColorSpace::Rec2020, ColorSpace::XyzD50, ColorSpace::XyzD65,
+];
+
+const SYSTEM_COLORS: [SystemColor; 19] = [
+    SystemColor::AccentColor, SystemColor::AccentColorText, SystemColor::ActiveText, SystemColor::ButtonBorder,
+    SystemColor::ButtonFace, SystemColor::ButtonText, SystemColor::Canvas, SystemColor::CanvasText,
+    SystemColor::Field, SystemColor::FieldText, SystemColor::GrayText, SystemColor::Highlight,
+    SystemColor::HighlightText, SystemColor::LinkText, SystemColor::Mark, SystemColor::MarkText,
+    SystemColor::SelectedItem, SystemColor::SelectedItemText, SystemColor::VisitedText,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::style::IntoCss;
+
+    use super::*;
+
+    fn parse(s: &str) -> Result<String, ParseColorError> {
+        s.parse::<Color>().map(IntoCss::into_css)
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse("#f00"), Ok("rgb(255, 0, 0)".to_string()));
+        assert_eq!(parse("#FF000080"), Ok("rgba(255, 0, 0, 0.5019607843137255)".to_string()));
+        assert_eq!(parse("#12345"), Err(ParseColorError::InvalidHex("#12345".to_string())));
+        assert_eq!(parse("#ggg"), Err(ParseColorError::InvalidHex("#ggg".to_string())));
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(parse("RebeccaPurple"), Ok("rebeccapurple".to_string()));
+        assert_eq!(parse("Rebecca"), Err(ParseColorError::UnknownName("Rebecca".to_string())));
+        assert_eq!(parse("DodgerBlue"), Ok("dodgerblue".to_string()));
+        assert_eq!(parse("currentcolor"), Ok("currentColor".to_string()));
+        assert_eq!(parse("canvastext"), Ok("CanvasText".to_string()));
+        assert_eq!(parse(" transparent "), Ok("transparent".to_string()));
+        assert_eq!(parse(""), Err(ParseColorError::Empty));
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(parse("rgb(100%, 0%, 50%)"), Ok("rgb(255, 0, 128)".to_string()));
+        assert_eq!(parse("rgba(1, 2, 3, 50%)"), Ok("rgba(1, 2, 3, 0.5)".to_string()));
+        assert_eq!(parse("rgb(1 2 3 / .25)"), Ok("rgba(1, 2, 3, 0.25)".to_string()));
+        assert_eq!(parse("hsl(0.5turn 50% 25%)"), Ok("hsl(180, 50%, 25%)".to_string()));
+        assert_eq!(parse("hsl(120, 50%, 25%, 0.5)"), Ok("hsla(120, 50%, 25%, 0.5)".to_string()));
+        assert_eq!(parse("hwb(90 none 20%)"), Ok("hwb(90 0% 20%)".to_string()));
+        assert_eq!(parse("lab(50% 40% -20 / 1)"), Ok("lab(50% 50 -20 / 1)".to_string()));
+        assert_eq!(parse("oklch(70% 0.1 250 / 50%)"), Ok("oklch(0.7 0.1 250 / 0.5)".to_string()));
+        assert_eq!(parse("color(display-p3 1 0 0)"), Ok("color(display-p3 1 0 0)".to_string()));
+        assert_eq!(parse("color(xyz 1 0 0)"), Err(ParseColorError::UnknownColorSpace("xyz".to_string())));
+        assert_eq!(parse("rgb(1 2)"), Err(ParseColorError::ComponentCount { function: "rgb".to_string(), count: 2 }));
+        assert_eq!(parse("rgb(1 2 3"), Err(ParseColorError::Unclosed));
+        assert_eq!(parse("rbg(1 2 3)"), Err(ParseColorError::UnknownFunction("rbg".to_string())));
+    }
+
+    #[test]
+    fn css_numbers() {
+        assert_eq!(parse_number("1"), Some(1.0));
+        assert_eq!(parse_number("-.5"), Some(-0.5));
+        assert_eq!(parse_number("+1.5e2"), Some(150.0));
+        assert_eq!(parse_number("1E-1"), Some(0.1));
+        for invalid in ["", "1.", ".", "e1", "1e", "1e+", "--1", "1_0", "0x1", "inf", "infinity", "NaN", "1e999"].iter() {
+            assert_eq!(parse_number(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn non_finite_components_are_rejected() {
+        assert_eq!(parse("rgb(nan, inf, 1)"), Err(ParseColorError::InvalidComponent("nan".to_string())));
+        assert_eq!(parse("hsl(infinity 50% 50%)"), Err(ParseColorError::InvalidComponent("infinity".to_string())));
+        assert_eq!(parse("oklch(0.5 0.1 infdeg)"), Err(ParseColorError::InvalidComponent("infdeg".to_string())));
+        assert_eq!(parse("lab(50% 1e999 0)"), Err(ParseColorError::InvalidComponent("1e999".to_string())));
+    }
+}
@@ -0,0 +1,137 @@
+//! Relative color syntax (https://www.w3.org/TR/css-color-5/#relative-colors).
+
+use crate::style::IntoCss;
+
+use super::{Color, ColorSpace};
+
+/// The color function of a relative color
+#[derive(Clone, Copy, Debug)]
+pub enum RelativeFunction {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    Color(ColorSpace),
+}
+
+impl RelativeFunction {
+    /// The keywords that refer to the channels of the origin color
+    fn channel_keywords(self) -> [&'static str; 3] {
+        match self {
+            Self::Rgb => ["r", "g", "b"],
+            Self::Hsl => ["h", "s", "l"],
+            Self::Hwb => ["h", "w", "b"],
+            Self::Lab | Self::Oklab => ["l", "a", "b"],
+            Self::Lch | Self::Oklch => ["l", "c", "h"],
+            Self::Color(ColorSpace::XyzD50) | Self::Color(ColorSpace::XyzD65) => ["x", "y", "z"],
+            Self::Color(_) => ["r", "g", "b"],
+        }
+    }
+}
+
+/// A channel of a relative color
+#[derive(Clone, Debug)]
+pub enum RelativeChannel {
+    /// The same channel of the origin color, e.g. `r` in `rgb()`
+    Same,
+    Number(f64),
+    Percent(f64),
+    /// Any other expression, e.g. `calc(l * 0.8)`
+    Expression(String),
+}
+
+impl From<f64> for RelativeChannel {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<&str> for RelativeChannel {
+    fn from(expression: &str) -> Self {
+        Self::Expression(expression.to_string())
+    }
+}
+
+/// `<function>(from <origin> <channel> <channel> <channel> / <alpha>)`, e.g. `rgb(from var(--x) r g b / 50%)`.
+///
+/// Relative colors are always resolved by the browser.
+#[derive(Clone, Debug)]
+pub struct RelativeColor {
+    pub function: RelativeFunction,
+    pub origin: Color,
+    pub channels: [RelativeChannel; 3],
+    /// The alpha of the origin color if `None`
+    pub alpha: Option<RelativeChannel>,
+}
+
+impl RelativeColor {
+    pub fn new(function: RelativeFunction, origin: impl Into<Color>, channels: [RelativeChannel; 3], alpha: Option<RelativeChannel>) -> Self {
+        Self { function, origin: origin.into(), channels, alpha }
+    }
+}
+
+impl IntoCss for RelativeColor {
+    fn into_css(self) -> String {
+        let keywords = self.function.channel_keywords();
+        let channel = |channel: RelativeChannel, keyword: &str| match channel {
+            RelativeChannel::Same => keyword.to_string(),
+            RelativeChannel::Number(number) => number.to_string(),
+            RelativeChannel::Percent(percent) => format!("{percent}%"),
+            RelativeChannel::Expression(expression) => expression,
+        };
+        let [a, b, c] = self.channels;
+        let mut channels = format!("{} {} {}", channel(a, keywords[0]), channel(b, keywords[1]), channel(c, keywords[2]));
+        if let Some(alpha) = self.alpha {
+            channels = format!("{channels} / {}", channel(alpha, "alpha"));
+        }
+        let origin = self.origin.into_css();
+        match self.function {
+            RelativeFunction::Rgb => format!("rgb(from {origin} {channels})"),
+            RelativeFunction::Hsl => format!("hsl(from {origin} {channels})"),
+            RelativeFunction::Hwb => format!("hwb(from {origin} {channels})"),
+            RelativeFunction::Lab => format!("lab(from {origin} {channels})"),
+            RelativeFunction::Lch => format!("lch(from {origin} {channels})"),
+            RelativeFunction::Oklab => format!("oklab(from {origin} {channels})"),
+            RelativeFunction::Oklch => format!("oklch(from {origin} {channels})"),
+            RelativeFunction::Color(space) => format!("color(from {origin} {} {channels})", space.into_css()),
+        }
+    }
+}
+
+impl From<RelativeColor> for Color {
+    fn from(color: RelativeColor) -> Self {
+        Self::Relative(Box::new(color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{color::{ColorFormat, NamedColor, SystemColor}, variable::var};
+
+    use super::*;
+
+    #[test]
+    fn relative_colors() {
+        let faded = RelativeColor::new(RelativeFunction::Rgb, var::<Color>("brand"),
+            [RelativeChannel::Same, RelativeChannel::Same, RelativeChannel::Same], Some(RelativeChannel::Percent(50.0)));
+        assert_eq!(faded.into_css(), "rgb(from var(--brand) r g b / 50%)");
+
+        let darker = RelativeColor::new(RelativeFunction::Oklch, NamedColor::Red,
+            ["calc(l * 0.8)".into(), RelativeChannel::Same, 120.0.into()], None);
+        assert_eq!(Color::from(darker).to_css_with(ColorFormat::Functional), "oklch(from red calc(l * 0.8) c 120)");
+
+        let xyz = RelativeColor::new(RelativeFunction::Color(ColorSpace::XyzD65), SystemColor::Canvas,
+            [RelativeChannel::Same, RelativeChannel::Number(0.5), RelativeChannel::Same], None);
+        assert_eq!(xyz.into_css(), "color(from Canvas xyz-d65 x 0.5 z)");
+    }
+
+    #[test]
+    fn relative_colors_are_resolved_by_the_browser() {
+        let color = Color::from(RelativeColor::new(RelativeFunction::Hsl, NamedColor::Red,
+            [RelativeChannel::Same, RelativeChannel::Same, RelativeChannel::Same], None));
+        assert!(color.to_srgb().is_none());
+    }
+}
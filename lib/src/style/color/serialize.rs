@@ -0,0 +1,146 @@
+//! Serialization of colors in different formats.
+
+use std::cell::Cell;
+
+use super::{Color, NamedColor};
+
+/// How a `Color` is written to css
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorFormat {
+    /// The color function or keyword the color was created with, e.g. `rgb(255, 255, 255)`
+    Functional,
+    /// `#rrggbb` or `#rrggbbaa` for colors in the sRGB gamut, shortened to `#rgb` or `#rgba` where possible.
+    /// Colors of the other color functions keep their function.
+    Hex,
+    /// The shortest of the functional, hex and named form, with the spaces after commas removed
+    Shortest,
+}
+
+thread_local! {
+    static DEFAULT_FORMAT: Cell<ColorFormat> = const { Cell::new(ColorFormat::Functional) };
+}
+
+/// Sets the format used by `Color::into_css` (and so by `CssBlock`) on the current thread, `Functional` by default.
+///
+/// Generated class names do not depend on the format, so a server and a client with different formats agree on them.
+pub fn set_default_format(format: ColorFormat) {
+    DEFAULT_FORMAT.with(|default| default.set(format));
+}
+
+pub(super) fn default_format() -> ColorFormat {
+    DEFAULT_FORMAT.with(Cell::get)
+}
+
+/// Runs `f` with the default format of the current thread set to `format`, e.g. to hash css independent of the format
+pub(crate) fn with_format<R>(format: ColorFormat, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous format, also if `f` panics
+    struct Restore(ColorFormat);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_default_format(self.0);
+        }
+    }
+
+    let _restore = Restore(default_format());
+    set_default_format(format);
+    f()
+}
+
+impl Color {
+    /// Serializes the color in `format`
+    pub fn to_css_with(&self, format: ColorFormat) -> String {
+        match format {
+            ColorFormat::Functional => self.to_functional(),
+            ColorFormat::Hex => self.to_hex().unwrap_or_else(|| self.to_functional()),
+            ColorFormat::Shortest => {
+                let mut shortest = self.to_functional().replace(", ", ",");
+                for candidate in [self.to_hex(), self.to_name()].iter().flatten() {
+                    if candidate.len() < shortest.len() {
+                        shortest = candidate.clone();
+                    }
+                }
+                shortest
+            },
+        }
+    }
+
+    /// The shortest hex notation of the color.
+    ///
+    /// `None` for colors that are not created in sRGB, converting them could clip them or lose precision.
+    pub fn to_hex(&self) -> Option<String> {
+        let (r, g, b, alpha) = self.srgb_bytes()?;
+        let mut bytes = vec![r, g, b];
+        if alpha < 255 {
+            bytes.push(alpha);
+        }
+        if bytes.iter().all(|byte| byte % 17 == 0) {
+            Some(bytes.iter().fold("#".to_string(), |hex, byte| format!("{hex}{:x}", byte / 17)))
+        } else {
+            Some(bytes.iter().fold("#".to_string(), |hex, byte| format!("{hex}{byte:02x}")))
+        }
+    }
+
+    /// The css name of the color if there is a named color (or `transparent`) with exactly these channels
+    fn to_name(&self) -> Option<String> {
+        match *self {
+            Self::Named(name) => Some(name.to_string()),
+            Self::Transparent => Some("transparent".to_string()),
+            _ => match self.srgb_bytes()? {
+                (0, 0, 0, 0) => Some("transparent".to_string()),
+                (r, g, b, 255) => NamedColor::ALL.iter()
+                    .find(|name| name.rgb() == (r, g, b))
+                    .map(|name| name.to_string()),
+                _ => None,
+            },
+        }
+    }
+
+    /// The channels and alpha as bytes, for the colors that are defined in sRGB
+    fn srgb_bytes(&self) -> Option<(u8, u8, u8, u8)> {
+        match self {
+            Self::RGB(..) | Self::RGBA(..) | Self::Named(_) | Self::Transparent | Self::HSL(..) | Self::HWB(..) => {
+                let (rgb, alpha) = self.srgb_channels()?;
+                let [r, g, b] = super::clamp(rgb).map(|v| (v * 255.0).round() as u8);
+                Some((r, g, b, (alpha.clamp(0.0, 1.0) * 255.0).round() as u8))
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex() {
+        assert_eq!(Color::RGB(255, 0, 0).to_hex(), Some("#f00".to_string()));
+        assert_eq!(Color::RGB(18, 52, 86).to_hex(), Some("#123456".to_string()));
+        assert_eq!(Color::RGBA(255, 255, 255, 0.5).to_hex(), Some("#ffffff80".to_string()));
+        assert_eq!(Color::hsl(120.0, 100.0, 50.0).to_hex(), Some("#0f0".to_string()));
+        // converting from other spaces could clip the color
+        assert_eq!(Color::oklch(0.7, 0.1, 250.0).to_hex(), None);
+        assert_eq!(Color::oklch(0.7, 0.1, 250.0).to_css_with(ColorFormat::Hex), "oklch(0.7 0.1 250)");
+    }
+
+    #[test]
+    fn shortest() {
+        let shortest = |color: Color| color.to_css_with(ColorFormat::Shortest);
+        assert_eq!(shortest(Color::RGB(255, 0, 0)), "red");
+        assert_eq!(shortest(Color::RGB(18, 52, 86)), "#123456");
+        assert_eq!(shortest(Color::RGBA(0, 0, 0, 0.0)), "#0000");
+        assert_eq!(shortest(Color::RGB(0, 0, 0)), "#000");
+        assert_eq!(shortest(Color::Named(NamedColor::Aliceblue)), "#f0f8ff");
+        assert_eq!(shortest(Color::lab(50.0, 20.0, -30.0)), "lab(50% 20 -30)");
+    }
+
+    #[test]
+    fn default_format_is_restored() {
+        set_default_format(ColorFormat::Hex);
+        let inner = with_format(ColorFormat::Functional, default_format);
+        assert_eq!(inner, ColorFormat::Functional);
+        assert_eq!(default_format(), ColorFormat::Hex);
+        set_default_format(ColorFormat::Functional);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::css_enum;
+
+use super::{media::{Condition, Orientation}, quantities::Length, IntoCss};
+
+css_enum!(ContainerType; Normal | Size | InlineSize "inline-size");
+
+/// The names a container can be queried by, `none` or a list of identifiers
+#[derive(Clone, Debug)]
+pub enum ContainerName {
+    None,
+    Names(Vec<String>),
+}
+
+impl IntoCss for ContainerName {
+    fn into_css(self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::Names(names) => names.join(" "),
+        }
+    }
+}
+
+/// A single name, or several separated by whitespace
+impl From<&str> for ContainerName {
+    fn from(names: &str) -> Self {
+        Self::Names(names.split_whitespace().map(str::to_string).collect())
+    }
+}
+
+/// The `container` shorthand, `name / type`
+#[derive(Clone, Debug)]
+pub struct Container {
+    pub name: ContainerName,
+    pub container_type: Option<ContainerType>,
+}
+
+impl Container {
+    pub fn new(name: impl Into<ContainerName>, container_type: ContainerType) -> Self {
+        Self { name: name.into(), container_type: Some(container_type) }
+    }
+}
+
+impl IntoCss for Container {
+    fn into_css(self) -> String {
+        match self.container_type {
+            Some(container_type) => format!("{} / {}", self.name.into_css(), container_type.into_css()),
+            None => self.name.into_css(),
+        }
+    }
+}
+
+impl From<ContainerName> for Container {
+    fn from(name: ContainerName) -> Self {
+        Self { name, container_type: None }
+    }
+}
+
+impl From<&str> for Container {
+    fn from(name: &str) -> Self {
+        ContainerName::from(name).into()
+    }
+}
+
+css_enum!(SizeFeature; Width | Height | InlineSize "inline-size" | BlockSize "block-size");
+css_enum!(Comparison; Less "<" | LessOrEqual "<=" | Equal "=" | GreaterOrEqual ">=" | Greater ">");
+
+/// A single test of a container query
+#[derive(Clone)]
+pub enum ContainerFeature {
+    /// A range, e.g. `(width >= 400px)`
+    Size(SizeFeature, Comparison, Length),
+    Orientation(Orientation),
+}
+
+impl IntoCss for ContainerFeature {
+    fn into_css(self) -> String {
+        match self {
+            Self::Size(feature, comparison, length) => format!("({} {} {})", feature.into_css(), comparison.into_css(), length.into_css()),
+            Self::Orientation(orientation) => format!("(orientation: {})", orientation.into_css()),
+        }
+    }
+}
+
+/// Container features combined with `and`, `or` and `not`
+pub type ContainerCondition = Condition<ContainerFeature>;
+
+impl ContainerCondition {
+    /// `(width >= min)`
+    pub fn min_width(min: impl Into<Length>) -> Self {
+        ContainerFeature::Size(SizeFeature::Width, Comparison::GreaterOrEqual, min.into()).into()
+    }
+
+    /// `(width <= max)`
+    pub fn max_width(max: impl Into<Length>) -> Self {
+        ContainerFeature::Size(SizeFeature::Width, Comparison::LessOrEqual, max.into()).into()
+    }
+
+    /// `(inline-size >= min)`
+    pub fn min_inline_size(min: impl Into<Length>) -> Self {
+        ContainerFeature::Size(SizeFeature::InlineSize, Comparison::GreaterOrEqual, min.into()).into()
+    }
+
+    /// `(inline-size <= max)`
+    pub fn max_inline_size(max: impl Into<Length>) -> Self {
+        ContainerFeature::Size(SizeFeature::InlineSize, Comparison::LessOrEqual, max.into()).into()
+    }
+}
+
+/// The query of an `@container` rule, the nearest ancestor container (with the name) is queried
+#[derive(Clone)]
+pub struct ContainerQuery {
+    pub name: Option<String>,
+    pub condition: ContainerCondition,
+}
+
+impl ContainerQuery {
+    /// Queries the container named `name`
+    pub fn named(name: &str, condition: ContainerCondition) -> Self {
+        Self { name: Some(name.to_string()), condition }
+    }
+}
+
+impl IntoCss for ContainerQuery {
+    fn into_css(self) -> String {
+        match self.name {
+            Some(name) => format!("{name} {}", self.condition.into_css()),
+            None => self.condition.into_css(),
+        }
+    }
+}
+
+impl From<ContainerCondition> for ContainerQuery {
+    fn from(condition: ContainerCondition) -> Self {
+        Self { name: None, condition }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css;
+
+    use super::*;
+
+    #[test]
+    fn declarations() {
+        assert_eq!(Container::new("card sidebar", ContainerType::InlineSize).into_css(), "card sidebar / inline-size");
+        assert_eq!(Container::from("card").into_css(), "card");
+        assert_eq!(ContainerName::None.into_css(), "none");
+    }
+
+    #[test]
+    fn queries() {
+        let condition = ContainerCondition::min_inline_size(400)
+            .and(!ContainerCondition::from(ContainerFeature::Orientation(Orientation::Portrait)))
+            .or(ContainerCondition::max_width(100));
+        assert_eq!(condition.clone().into_css(), "((inline-size >= 400px) and (not (orientation: portrait))) or (width <= 100px)");
+        assert_eq!(ContainerQuery::named("card", condition).into_css(), "card ((inline-size >= 400px) and (not (orientation: portrait))) or (width <= 100px)");
+
+        let mut title = css!{ width: 1.0; };
+        title.set_container_query(ContainerQuery::from(ContainerCondition::min_width(400)), css!{ width: 0.5; });
+        assert_eq!(title.to_rules(".title")[1], "@container (width >= 400px) {.title {width: 50%;}}");
+    }
+}
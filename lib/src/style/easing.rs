@@ -0,0 +1,392 @@
+use crate::css_enum;
+
+use super::{quantities::Duration, IntoCss};
+
+/// A timing function of transitions and animations.
+///
+/// `eval` computes the output progress in rust exactly like the browser does, e.g. to drive an animation from rust.
+#[derive(Clone, Debug)]
+pub enum Easing {
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    StepStart,
+    StepEnd,
+    CubicBezier(CubicBezier),
+    Steps(Steps),
+    /// `linear()` with stops
+    Points(LinearPoints),
+}
+
+impl Easing {
+    /// `cubic-bezier(x1, y1, x2, y2)`, `None` if an x value is not within `0..=1`
+    pub fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Option<Self> {
+        CubicBezier::new(x1, y1, x2, y2).map(Self::CubicBezier)
+    }
+
+    /// `steps(count, position)`, `None` if there are no steps (or less than two for `jump-none`)
+    pub fn steps(count: u32, position: StepPosition) -> Option<Self> {
+        Steps::new(count, position).map(Self::Steps)
+    }
+
+    /// `linear(0, 0.25 75%, 1)`, see `LinearPoints::new`
+    pub fn linear(points: Vec<(f64, Option<f64>)>) -> Option<Self> {
+        LinearPoints::new(points).map(Self::Points)
+    }
+
+    /// The output progress at the input progress `t`, usually within `0..=1`
+    pub fn eval(&self, t: f64) -> f64 {
+        match self {
+            Self::Linear => t,
+            Self::Ease => CubicBezier::EASE.eval(t),
+            Self::EaseIn => CubicBezier::EASE_IN.eval(t),
+            Self::EaseOut => CubicBezier::EASE_OUT.eval(t),
+            Self::EaseInOut => CubicBezier::EASE_IN_OUT.eval(t),
+            Self::StepStart => Steps { count: 1, position: StepPosition::JumpStart }.eval(t),
+            Self::StepEnd => Steps { count: 1, position: StepPosition::JumpEnd }.eval(t),
+            Self::CubicBezier(bezier) => bezier.eval(t),
+            Self::Steps(steps) => steps.eval(t),
+            Self::Points(points) => points.eval(t),
+        }
+    }
+}
+
+/// The `duration easing delay` parts of the `animation` and `transition` shorthands, empty for the ones that are not set
+pub(crate) fn timing_parts(duration: Option<Duration>, easing: Option<Easing>, delay: Option<Duration>) -> [String; 3] {
+    // the first time is the duration, so it is needed before a delay
+    let duration = match (duration, &delay) {
+        (None, Some(_)) => "0s".to_string(),
+        (duration, _) => duration.into_css(),
+    };
+    [duration, easing.into_css(), delay.into_css()]
+}
+
+impl IntoCss for Easing {
+    fn into_css(self) -> String {
+        match self {
+            Self::Linear => "linear".to_string(),
+            Self::Ease => "ease".to_string(),
+            Self::EaseIn => "ease-in".to_string(),
+            Self::EaseOut => "ease-out".to_string(),
+            Self::EaseInOut => "ease-in-out".to_string(),
+            Self::StepStart => "step-start".to_string(),
+            Self::StepEnd => "step-end".to_string(),
+            Self::CubicBezier(bezier) => bezier.into_css(),
+            Self::Steps(steps) => steps.into_css(),
+            Self::Points(points) => points.into_css(),
+        }
+    }
+}
+
+/// A cubic bézier curve from `(0, 0)` to `(1, 1)` with the control points `(x1, y1)` and `(x2, y2)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+impl CubicBezier {
+    pub const EASE: CubicBezier = CubicBezier { x1: 0.25, y1: 0.1, x2: 0.25, y2: 1.0 };
+    pub const EASE_IN: CubicBezier = CubicBezier { x1: 0.42, y1: 0.0, x2: 1.0, y2: 1.0 };
+    pub const EASE_OUT: CubicBezier = CubicBezier { x1: 0.0, y1: 0.0, x2: 0.58, y2: 1.0 };
+    pub const EASE_IN_OUT: CubicBezier = CubicBezier { x1: 0.42, y1: 0.0, x2: 0.58, y2: 1.0 };
+
+    /// `None` if an x value is not within `0..=1`, the y values are not limited
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Option<Self> {
+        let valid = |x: f64| (0.0..=1.0).contains(&x);
+        if valid(x1) && valid(x2) && y1.is_finite() && y2.is_finite() {
+            Some(Self { x1, y1, x2, y2 })
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> (f64, f64, f64, f64) {
+        (self.x1, self.y1, self.x2, self.y2)
+    }
+
+    /// The y value at the x value `t`, outside of `0..=1` the curve is extended along the tangents of its ends
+    pub fn eval(&self, t: f64) -> f64 {
+        if t < 0.0 {
+            let slope = if self.x1 > 0.0 {
+                self.y1 / self.x1
+            } else if self.y1 == 0.0 && self.x2 > 0.0 {
+                self.y2 / self.x2
+            } else {
+                0.0
+            };
+            return slope * t;
+        }
+        if t > 1.0 {
+            let slope = if self.x2 < 1.0 {
+                (self.y2 - 1.0) / (self.x2 - 1.0)
+            } else if self.y2 == 1.0 && self.x1 < 1.0 {
+                (self.y1 - 1.0) / (self.x1 - 1.0)
+            } else {
+                0.0
+            };
+            return 1.0 + slope * (t - 1.0);
+        }
+        bezier(self.y1, self.y2, self.solve_x(t))
+    }
+
+    /// The curve parameter at which the x value is `x`
+    fn solve_x(&self, x: f64) -> f64 {
+        // newton's method converges fast unless the slope is close to 0
+        let mut s = x;
+        for _ in 0..8 {
+            let error = bezier(self.x1, self.x2, s) - x;
+            if error.abs() < 1e-9 {
+                return s;
+            }
+            let slope = bezier_slope(self.x1, self.x2, s);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            s -= error / slope;
+        }
+        // x increases monotonically with the parameter since both x values are within 0..=1
+        let (mut low, mut high) = (0.0, 1.0);
+        s = x;
+        while high - low > 1e-9 {
+            if bezier(self.x1, self.x2, s) < x {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = (low + high) / 2.0;
+        }
+        s
+    }
+}
+
+impl IntoCss for CubicBezier {
+    fn into_css(self) -> String {
+        format!("cubic-bezier({}, {}, {}, {})", self.x1, self.y1, self.x2, self.y2)
+    }
+}
+
+/// One coordinate of a cubic bézier curve from 0 to 1 with the control values `a` and `b` at the parameter `s`
+fn bezier(a: f64, b: f64, s: f64) -> f64 {
+    3.0 * a * s * (1.0 - s).powi(2) + 3.0 * b * s.powi(2) * (1.0 - s) + s.powi(3)
+}
+
+fn bezier_slope(a: f64, b: f64, s: f64) -> f64 {
+    3.0 * a * (1.0 - s).powi(2) + 6.0 * (b - a) * s * (1.0 - s) + 3.0 * (1.0 - b) * s.powi(2)
+}
+
+css_enum!(StepPosition;
+    JumpStart "jump-start" | JumpEnd "jump-end" | JumpNone "jump-none" | JumpBoth "jump-both" | Start | End
+);
+
+/// `steps(count, position)`, the output jumps between `count` equal steps
+#[derive(Clone, Copy, Debug)]
+pub struct Steps {
+    count: u32,
+    position: StepPosition,
+}
+
+impl Steps {
+    /// `None` if there are no steps, or less than two for `jump-none`
+    pub fn new(count: u32, position: StepPosition) -> Option<Self> {
+        let min = if matches!(position, StepPosition::JumpNone) { 2 } else { 1 };
+        if count >= min {
+            Some(Self { count, position })
+        } else {
+            None
+        }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn position(&self) -> StepPosition {
+        self.position
+    }
+
+    pub fn eval(&self, t: f64) -> f64 {
+        let count = self.count as f64;
+        let mut step = (t * count).floor();
+        if matches!(self.position, StepPosition::JumpStart | StepPosition::Start | StepPosition::JumpBoth) {
+            step += 1.0;
+        }
+        let jumps = match self.position {
+            StepPosition::JumpNone => count - 1.0,
+            StepPosition::JumpBoth => count + 1.0,
+            _ => count,
+        };
+        if t >= 0.0 && step < 0.0 {
+            step = 0.0;
+        }
+        if t <= 1.0 && step > jumps {
+            step = jumps;
+        }
+        step / jumps
+    }
+}
+
+impl IntoCss for Steps {
+    fn into_css(self) -> String {
+        format!("steps({}, {})", self.count, self.position.into_css())
+    }
+}
+
+/// The stops of a `linear()` easing, the output progress with an optional input progress in percent.
+///
+/// Stops without an input are spread evenly between their neighbours, like in the browser.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearPoints {
+    points: Vec<(f64, Option<f64>)>,
+}
+
+impl LinearPoints {
+    /// `None` if there are less than two stops
+    pub fn new(points: Vec<(f64, Option<f64>)>) -> Option<Self> {
+        if points.len() >= 2 {
+            Some(Self { points })
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> &[(f64, Option<f64>)] {
+        &self.points
+    }
+
+    /// The `(input, output)` points with every input resolved to a fraction
+    fn resolve(&self) -> Vec<(f64, f64)> {
+        let last = self.points.len() - 1;
+        let mut inputs: Vec<Option<f64>> = self.points.iter().map(|(_, input)| input.map(|input| input / 100.0)).collect();
+        inputs[0] = inputs[0].or(Some(0.0));
+        inputs[last] = inputs[last].or(Some(1.0));
+        // an input can not be smaller than one before it
+        let mut max = f64::NEG_INFINITY;
+        for input in inputs.iter_mut().flatten() {
+            max = max.max(*input);
+            *input = max;
+        }
+        let mut i = 0;
+        while i < last {
+            let start = i;
+            i += 1;
+            while inputs[i].is_none() {
+                i += 1;
+            }
+            let (from, to) = (inputs[start].unwrap_or(0.0), inputs[i].unwrap_or(1.0));
+            for (j, input) in inputs.iter_mut().enumerate().take(i).skip(start + 1) {
+                *input = Some(from + (to - from) * (j - start) as f64 / (i - start) as f64);
+            }
+        }
+        self.points.iter().zip(inputs).map(|((output, _), input)| (input.unwrap_or(0.0), *output)).collect()
+    }
+
+    pub fn eval(&self, t: f64) -> f64 {
+        let points = self.resolve();
+        let last = points.len() - 1;
+        let i = if t < points[0].0 {
+            0
+        } else if t >= points[last].0 {
+            last - 1
+        } else {
+            points.iter().rposition(|(input, _)| *input <= t).unwrap_or(0).min(last - 1)
+        };
+        let ((x1, y1), (x2, y2)) = (points[i], points[i + 1]);
+        if x1 == x2 {
+            y2
+        } else {
+            y1 + (y2 - y1) * (t - x1) / (x2 - x1)
+        }
+    }
+}
+
+impl IntoCss for LinearPoints {
+    fn into_css(self) -> String {
+        let points: Vec<String> = self.points.into_iter().map(|(output, input)| match input {
+            Some(input) => format!("{output} {input}%"),
+            None => output.to_string(),
+        }).collect();
+        format!("linear({})", points.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        for t in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_close(Easing::cubic_bezier(0.0, 0.0, 1.0, 1.0).unwrap().eval(t), t);
+            assert_close(Easing::Linear.eval(t), t);
+        }
+        assert_close(Easing::Ease.eval(0.0), 0.0);
+        assert_close(Easing::Ease.eval(1.0), 1.0);
+        assert!((Easing::Ease.eval(0.5) - 0.8024).abs() < 1e-4);
+        assert_close(Easing::EaseInOut.eval(0.5), 0.5);
+        assert!(Easing::EaseIn.eval(0.25) < 0.25 && Easing::EaseOut.eval(0.25) > 0.25);
+        // overshooting curves are allowed, the y values are not limited
+        let back = Easing::cubic_bezier(0.3, -0.5, 0.7, 1.5).unwrap();
+        assert!(back.eval(0.1) < 0.0 && back.eval(0.9) > 1.0);
+        assert!(Easing::cubic_bezier(1.5, 0.0, 0.5, 1.0).is_none());
+        assert!(Easing::cubic_bezier(0.5, f64::NAN, 0.5, 1.0).is_none());
+    }
+
+    #[test]
+    fn cubic_bezier_is_extended_along_the_tangents() {
+        let bezier = CubicBezier::new(0.5, 0.25, 0.5, 0.75).unwrap();
+        assert_close(bezier.eval(-1.0), -0.5);
+        assert_close(bezier.eval(2.0), 1.5);
+        assert_close(CubicBezier::EASE_IN.eval(-1.0), 0.0);
+    }
+
+    #[test]
+    fn steps() {
+        let eval = |count, position, t| Easing::steps(count, position).unwrap().eval(t);
+        assert_close(eval(4, StepPosition::JumpEnd, 0.0), 0.0);
+        assert_close(eval(4, StepPosition::JumpEnd, 0.5), 0.5);
+        assert_close(eval(4, StepPosition::JumpEnd, 0.99), 0.75);
+        assert_close(eval(4, StepPosition::JumpEnd, 1.0), 1.0);
+        assert_close(eval(4, StepPosition::JumpStart, 0.0), 0.25);
+        assert_close(eval(3, StepPosition::JumpNone, 0.5), 0.5);
+        assert_close(eval(3, StepPosition::JumpNone, 0.0), 0.0);
+        assert_close(eval(3, StepPosition::JumpBoth, 0.0), 0.25);
+        assert_close(eval(3, StepPosition::JumpBoth, 1.0), 1.0);
+        assert_close(Easing::StepStart.eval(0.0), 1.0);
+        assert_close(Easing::StepEnd.eval(0.99), 0.0);
+        assert!(Easing::steps(0, StepPosition::End).is_none());
+        assert!(Easing::steps(1, StepPosition::JumpNone).is_none());
+    }
+
+    #[test]
+    fn linear_points() {
+        let easing = Easing::linear(vec![(0.0, None), (0.25, Some(75.0)), (1.0, None)]).unwrap();
+        assert_close(easing.eval(0.375), 0.125);
+        assert_close(easing.eval(0.75), 0.25);
+        assert_close(easing.eval(0.875), 0.625);
+        // stops without an input are spread evenly
+        let even = Easing::linear(vec![(0.0, None), (0.5, None), (0.6, None), (1.0, None)]).unwrap();
+        assert_close(even.eval(1.0 / 3.0), 0.5);
+        assert_close(even.eval(0.5), 0.55);
+        // an input smaller than the one before it is raised
+        let raised = LinearPoints::new(vec![(0.0, None), (0.5, Some(50.0)), (0.7, Some(20.0)), (1.0, None)]).unwrap();
+        assert_close(raised.eval(0.5), 0.7);
+        assert!(Easing::linear(vec![(0.0, None)]).is_none());
+    }
+
+    #[test]
+    fn serialization() {
+        assert_eq!(Easing::cubic_bezier(0.1, 0.7, 1.0, 0.1).unwrap().into_css(), "cubic-bezier(0.1, 0.7, 1, 0.1)");
+        assert_eq!(Easing::steps(4, StepPosition::JumpEnd).unwrap().into_css(), "steps(4, jump-end)");
+        assert_eq!(Easing::linear(vec![(0.0, None), (0.25, Some(75.0)), (1.0, None)]).unwrap().into_css(), "linear(0, 0.25 75%, 1)");
+        assert_eq!(Easing::StepStart.into_css(), "step-start");
+    }
+}
@@ -0,0 +1,588 @@
+use crate::css_enum;
+
+use super::{quantities::{Angle, Length}, selector::{escape_identifier, escape_string}, CommaList, IntoCss};
+
+css_enum!(GenericFamily;
+    Serif | SansSerif "sans-serif" | Monospace | Cursive | Fantasy | SystemUi "system-ui" | UiSerif "ui-serif" |
+    UiSansSerif "ui-sans-serif" | UiMonospace "ui-monospace" | UiRounded "ui-rounded" | Math | Emoji | Fangsong
+);
+
+/// A font family name, which is quoted, or a generic family
+#[derive(Clone, Debug)]
+pub enum FontFamily {
+    Name(String),
+    Generic(GenericFamily),
+}
+
+impl IntoCss for FontFamily {
+    fn into_css(self) -> String {
+        match self {
+            Self::Name(name) => escape_string(&name),
+            Self::Generic(generic) => generic.into_css(),
+        }
+    }
+}
+
+impl From<&str> for FontFamily {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<GenericFamily> for FontFamily {
+    fn from(generic: GenericFamily) -> Self {
+        Self::Generic(generic)
+    }
+}
+
+impl From<&str> for CommaList<FontFamily> {
+    fn from(name: &str) -> Self {
+        CommaList(vec![name.into()])
+    }
+}
+
+impl From<GenericFamily> for CommaList<FontFamily> {
+    fn from(generic: GenericFamily) -> Self {
+        CommaList(vec![generic.into()])
+    }
+}
+
+/// The weight of a font, a number within `1..=1000` or a keyword
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FontWeight {
+    Normal,
+    Bold,
+    /// Bolder than the weight of the parent
+    Bolder,
+    /// Lighter than the weight of the parent
+    Lighter,
+    Number(f64),
+}
+
+impl FontWeight {
+    pub const THIN: FontWeight = FontWeight::Number(100.0);
+    pub const LIGHT: FontWeight = FontWeight::Number(300.0);
+    pub const REGULAR: FontWeight = FontWeight::Number(400.0);
+    pub const MEDIUM: FontWeight = FontWeight::Number(500.0);
+    pub const SEMI_BOLD: FontWeight = FontWeight::Number(600.0);
+    pub const BOLD: FontWeight = FontWeight::Number(700.0);
+    pub const BLACK: FontWeight = FontWeight::Number(900.0);
+
+    /// `None` if the weight is not within `1..=1000`
+    pub fn number(weight: f64) -> Option<Self> {
+        if (1.0..=1000.0).contains(&weight) {
+            Some(Self::Number(weight))
+        } else {
+            None
+        }
+    }
+}
+
+impl IntoCss for FontWeight {
+    fn into_css(self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Bold => "bold".to_string(),
+            Self::Bolder => "bolder".to_string(),
+            Self::Lighter => "lighter".to_string(),
+            Self::Number(weight) => weight.to_string(),
+        }
+    }
+}
+
+/// Numbers outside of `1..=1000` are clamped
+impl From<i64> for FontWeight {
+    fn from(weight: i64) -> Self {
+        Self::Number(weight.clamp(1, 1000) as f64)
+    }
+}
+
+css_enum!(FontSizeKeyword;
+    XxSmall "xx-small" | XSmall "x-small" | Small | Medium | Large | XLarge "x-large" | XxLarge "xx-large" |
+    XxxLarge "xxx-large" | Smaller | Larger
+);
+
+#[derive(Clone)]
+pub enum FontSize {
+    Keyword(FontSizeKeyword),
+    Length(Length),
+}
+
+impl IntoCss for FontSize {
+    fn into_css(self) -> String {
+        match self {
+            Self::Keyword(keyword) => keyword.into_css(),
+            Self::Length(length) => length.into_css(),
+        }
+    }
+}
+
+impl From<FontSizeKeyword> for FontSize {
+    fn from(keyword: FontSizeKeyword) -> Self {
+        Self::Keyword(keyword)
+    }
+}
+
+impl From<Length> for FontSize {
+    fn from(length: Length) -> Self {
+        Self::Length(length)
+    }
+}
+
+impl From<i64> for FontSize {
+    fn from(size: i64) -> Self {
+        Self::Length(size.into())
+    }
+}
+
+impl From<f64> for FontSize {
+    fn from(size: f64) -> Self {
+        Self::Length(size.into())
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    /// A slanted font, optionally with the angle of the slant
+    Oblique(Option<Angle>),
+}
+
+impl IntoCss for FontStyle {
+    fn into_css(self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Italic => "italic".to_string(),
+            Self::Oblique(Some(angle)) => format!("oblique {}", angle.into_css()),
+            Self::Oblique(None) => "oblique".to_string(),
+        }
+    }
+}
+
+css_enum!(FontStretch;
+    UltraCondensed "ultra-condensed" | ExtraCondensed "extra-condensed" | Condensed | SemiCondensed "semi-condensed" |
+    Normal | SemiExpanded "semi-expanded" | Expanded | ExtraExpanded "extra-expanded" | UltraExpanded "ultra-expanded"
+);
+css_enum!(FontKerning; Auto | Normal | None);
+css_enum!(FontOpticalSizing; Auto | None);
+css_enum!(FontSynthesisMode; Auto | None);
+css_enum!(FontVariantCaps;
+    Normal | SmallCaps "small-caps" | AllSmallCaps "all-small-caps" | PetiteCaps "petite-caps" |
+    AllPetiteCaps "all-petite-caps" | Unicase | TitlingCaps "titling-caps"
+);
+css_enum!(FontVariantEmoji; Normal | Text | Emoji | Unicode);
+css_enum!(FontVariantLigatures;
+    Normal | None | CommonLigatures "common-ligatures" | NoCommonLigatures "no-common-ligatures" |
+    DiscretionaryLigatures "discretionary-ligatures" | NoDiscretionaryLigatures "no-discretionary-ligatures" |
+    HistoricalLigatures "historical-ligatures" | NoHistoricalLigatures "no-historical-ligatures" |
+    Contextual | NoContextual "no-contextual"
+);
+css_enum!(FontVariantNumeric;
+    Normal | LiningNums "lining-nums" | OldstyleNums "oldstyle-nums" | ProportionalNums "proportional-nums" |
+    TabularNums "tabular-nums" | DiagonalFractions "diagonal-fractions" | StackedFractions "stacked-fractions" |
+    Ordinal | SlashedZero "slashed-zero"
+);
+css_enum!(FontVariantPosition; Normal | Sub | Super);
+css_enum!(FontVariantEastAsian;
+    Normal | Jis78 | Jis83 | Jis90 | Jis04 | Simplified | Traditional | FullWidth "full-width" |
+    ProportionalWidth "proportional-width" | Ruby
+);
+
+/// The `font-variant` shorthand, a value of one of the `font-variant-*` fields
+#[derive(Clone, Debug)]
+pub enum FontVariant {
+    Normal,
+    /// No ligatures, the other variants are `normal`
+    None,
+    Caps(FontVariantCaps),
+    Ligatures(FontVariantLigatures),
+    Numeric(FontVariantNumeric),
+    Position(FontVariantPosition),
+    EastAsian(FontVariantEastAsian),
+    Emoji(FontVariantEmoji),
+    Alternates(FontVariantAlternates),
+    /// Several values separated by spaces, e.g. `small-caps tabular-nums`, written as they are
+    Raw(String),
+}
+
+impl IntoCss for FontVariant {
+    fn into_css(self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::None => "none".to_string(),
+            Self::Caps(caps) => caps.into_css(),
+            Self::Ligatures(ligatures) => ligatures.into_css(),
+            Self::Numeric(numeric) => numeric.into_css(),
+            Self::Position(position) => position.into_css(),
+            Self::EastAsian(east_asian) => east_asian.into_css(),
+            Self::Emoji(emoji) => emoji.into_css(),
+            Self::Alternates(alternates) => alternates.into_css(),
+            Self::Raw(raw) => raw,
+        }
+    }
+}
+
+impl From<FontVariantCaps> for FontVariant {
+    fn from(caps: FontVariantCaps) -> Self {
+        Self::Caps(caps)
+    }
+}
+
+impl From<FontVariantLigatures> for FontVariant {
+    fn from(ligatures: FontVariantLigatures) -> Self {
+        Self::Ligatures(ligatures)
+    }
+}
+
+impl From<FontVariantNumeric> for FontVariant {
+    fn from(numeric: FontVariantNumeric) -> Self {
+        Self::Numeric(numeric)
+    }
+}
+
+impl From<FontVariantPosition> for FontVariant {
+    fn from(position: FontVariantPosition) -> Self {
+        Self::Position(position)
+    }
+}
+
+impl From<FontVariantEastAsian> for FontVariant {
+    fn from(east_asian: FontVariantEastAsian) -> Self {
+        Self::EastAsian(east_asian)
+    }
+}
+
+impl From<FontVariantEmoji> for FontVariant {
+    fn from(emoji: FontVariantEmoji) -> Self {
+        Self::Emoji(emoji)
+    }
+}
+
+impl From<FontVariantAlternates> for FontVariant {
+    fn from(alternates: FontVariantAlternates) -> Self {
+        Self::Alternates(alternates)
+    }
+}
+
+/// Alternate glyphs, the functions take names defined in `@font-feature-values` rules
+#[derive(Clone, Debug, PartialEq)]
+pub enum FontVariantAlternates {
+    Normal,
+    HistoricalForms,
+    Stylistic(String),
+    Styleset(Vec<String>),
+    CharacterVariant(Vec<String>),
+    Swash(String),
+    Ornaments(String),
+    Annotation(String),
+    /// Several values separated by spaces, written as they are
+    Raw(String),
+}
+
+impl IntoCss for FontVariantAlternates {
+    fn into_css(self) -> String {
+        let names = |names: Vec<String>| names.iter().map(|name| escape_identifier(name)).collect::<Vec<_>>().join(", ");
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::HistoricalForms => "historical-forms".to_string(),
+            Self::Stylistic(name) => format!("stylistic({})", escape_identifier(&name)),
+            Self::Styleset(sets) => format!("styleset({})", names(sets)),
+            Self::CharacterVariant(variants) => format!("character-variant({})", names(variants)),
+            Self::Swash(name) => format!("swash({})", escape_identifier(&name)),
+            Self::Ornaments(name) => format!("ornaments({})", escape_identifier(&name)),
+            Self::Annotation(name) => format!("annotation({})", escape_identifier(&name)),
+            Self::Raw(raw) => raw,
+        }
+    }
+}
+
+/// Which missing typefaces the browser may synthesize, `none` if none of them
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FontSynthesis {
+    pub weight: bool,
+    pub style: bool,
+    pub small_caps: bool,
+    pub position: bool,
+}
+
+impl FontSynthesis {
+    pub const NONE: FontSynthesis = FontSynthesis { weight: false, style: false, small_caps: false, position: false };
+    pub const ALL: FontSynthesis = FontSynthesis { weight: true, style: true, small_caps: true, position: true };
+}
+
+impl IntoCss for FontSynthesis {
+    fn into_css(self) -> String {
+        let parts: Vec<&str> = [(self.weight, "weight"), (self.style, "style"), (self.small_caps, "small-caps"), (self.position, "position")]
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, name)| *name)
+            .collect();
+        if parts.is_empty() {
+            "none".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+css_enum!(FontMetric; ExHeight "ex-height" | CapHeight "cap-height" | ChWidth "ch-width" | IcWidth "ic-width" | IcHeight "ic-height");
+
+/// `font-size-adjust`, the size of the fallback fonts is adjusted so the metric is the font size times the number
+#[derive(Clone, Copy, Debug)]
+pub enum FontSizeAdjust {
+    None,
+    /// The metric of the first available font
+    FromFont,
+    /// The ratio of the `ex-height`
+    Number(f64),
+    /// The ratio of another metric, `None` for the one of the first available font
+    Metric(FontMetric, Option<f64>),
+}
+
+impl IntoCss for FontSizeAdjust {
+    fn into_css(self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::FromFont => "from-font".to_string(),
+            Self::Number(number) => number.to_string(),
+            Self::Metric(metric, Some(number)) => format!("{} {number}", metric.into_css()),
+            Self::Metric(metric, None) => format!("{} from-font", metric.into_css()),
+        }
+    }
+}
+
+impl From<f64> for FontSizeAdjust {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+/// The color palette of a color font
+#[derive(Clone, Debug, PartialEq)]
+pub enum FontPalette {
+    Normal,
+    Light,
+    Dark,
+    /// A palette defined by a `@font-palette-values` rule, the name starts with `--`
+    Custom(String),
+    /// E.g. `palette-mix()`, written as it is
+    Raw(String),
+}
+
+impl IntoCss for FontPalette {
+    fn into_css(self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Light => "light".to_string(),
+            Self::Dark => "dark".to_string(),
+            Self::Custom(name) => escape_identifier(&name),
+            Self::Raw(raw) => raw,
+        }
+    }
+}
+
+/// The OpenType language system of `font-language-override`, or `normal` for the one of the `lang` attribute
+#[derive(Clone, Debug, PartialEq)]
+pub enum FontLanguageOverride {
+    Normal,
+    /// An OpenType language tag, e.g. `TRK`
+    Tag(String),
+}
+
+impl IntoCss for FontLanguageOverride {
+    fn into_css(self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Tag(tag) => escape_string(&tag),
+        }
+    }
+}
+
+impl From<&str> for FontLanguageOverride {
+    fn from(tag: &str) -> Self {
+        Self::Tag(tag.to_string())
+    }
+}
+
+/// An OpenType feature of `font-feature-settings`, e.g. `"tnum" 1`
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontFeature {
+    tag: String,
+    value: u32,
+}
+
+impl FontFeature {
+    /// `None` if the tag is not four printable ASCII characters
+    pub fn new(tag: &str, value: u32) -> Option<Self> {
+        if valid_tag(tag) {
+            Some(Self { tag: tag.to_string(), value })
+        } else {
+            None
+        }
+    }
+}
+
+impl IntoCss for FontFeature {
+    fn into_css(self) -> String {
+        format!("{} {}", escape_string(&self.tag), self.value)
+    }
+}
+
+/// A variation axis of `font-variation-settings`, e.g. `"wght" 650`
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontVariation {
+    axis: String,
+    value: f64,
+}
+
+impl FontVariation {
+    /// `None` if the axis is not four printable ASCII characters
+    pub fn new(axis: &str, value: f64) -> Option<Self> {
+        if valid_tag(axis) {
+            Some(Self { axis: axis.to_string(), value })
+        } else {
+            None
+        }
+    }
+}
+
+impl IntoCss for FontVariation {
+    fn into_css(self) -> String {
+        format!("{} {}", escape_string(&self.axis), self.value)
+    }
+}
+
+/// OpenType tags are four characters from U+20 to U+7E
+fn valid_tag(tag: &str) -> bool {
+    tag.len() == 4 && tag.bytes().all(|byte| (0x20..=0x7e).contains(&byte))
+}
+
+/// The `font` shorthand, the size and families are required
+#[derive(Clone)]
+pub struct Font {
+    pub style: Option<FontStyle>,
+    pub small_caps: bool,
+    pub weight: Option<FontWeight>,
+    pub stretch: Option<FontStretch>,
+    pub size: FontSize,
+    pub line_height: Option<Length>,
+    pub families: CommaList<FontFamily>,
+}
+
+impl Font {
+    pub fn new(size: impl Into<FontSize>, families: impl Into<CommaList<FontFamily>>) -> Self {
+        Self {
+            style: None,
+            small_caps: false,
+            weight: None,
+            stretch: None,
+            size: size.into(),
+            line_height: None,
+            families: families.into(),
+        }
+    }
+
+    pub fn style(mut self, style: FontStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn small_caps(mut self) -> Self {
+        self.small_caps = true;
+        self
+    }
+
+    pub fn weight(mut self, weight: impl Into<FontWeight>) -> Self {
+        self.weight = Some(weight.into());
+        self
+    }
+
+    pub fn stretch(mut self, stretch: FontStretch) -> Self {
+        self.stretch = Some(stretch);
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<Length>) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+}
+
+impl IntoCss for Font {
+    fn into_css(self) -> String {
+        let mut parts = vec![
+            self.style.into_css(),
+            if self.small_caps { "small-caps".to_string() } else { String::new() },
+            self.weight.into_css(),
+            self.stretch.into_css(),
+        ];
+        match self.line_height {
+            Some(line_height) => parts.push(format!("{}/{}", self.size.into_css(), line_height.into_css())),
+            None => parts.push(self.size.into_css()),
+        }
+        parts.push(self.families.into_css());
+        parts.retain(|part| !part.is_empty());
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css;
+
+    use super::*;
+
+    #[test]
+    fn families_and_shorthand() {
+        let families: CommaList<FontFamily> = CommaList(vec!["Brand \"Sans\"".into(), GenericFamily::SansSerif.into()]);
+        assert_eq!(families.clone().into_css(), "\"Brand \\\"Sans\\\"\", sans-serif");
+        let font = Font::new(Length::Rem(1.0), families).style(FontStyle::Italic).weight(700).line_height(Length::Em(1.5)).small_caps();
+        assert_eq!(font.into_css(), "italic small-caps 700 1rem/1.5em \"Brand \\\"Sans\\\"\", sans-serif");
+        assert_eq!(FontWeight::from(5000).into_css(), "1000");
+        assert!(FontWeight::number(0.5).is_none());
+        assert!(FontFeature::new("tnu", 1).is_none());
+        assert_eq!(FontVariation::new("wght", 650.0).unwrap().into_css(), "\"wght\" 650");
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(FontVariant::from(FontVariantCaps::SmallCaps).into_css(), "small-caps");
+        assert_eq!(FontVariant::Raw("small-caps tabular-nums".into()).into_css(), "small-caps tabular-nums");
+        assert_eq!(FontVariantEastAsian::FullWidth.into_css(), "full-width");
+        assert_eq!(FontVariantAlternates::Styleset(vec!["alt a".into(), "b".into()]).into_css(), "styleset(alt\\ a, b)");
+        assert_eq!(FontVariant::from(FontVariantAlternates::HistoricalForms).into_css(), "historical-forms");
+    }
+
+    #[test]
+    fn typed_fields() {
+        assert_eq!(FontSynthesis::NONE.into_css(), "none");
+        assert_eq!(FontSynthesis { weight: true, small_caps: true, ..FontSynthesis::NONE }.into_css(), "weight small-caps");
+        assert_eq!(FontSizeAdjust::from(0.5).into_css(), "0.5");
+        assert_eq!(FontSizeAdjust::Metric(FontMetric::CapHeight, None).into_css(), "cap-height from-font");
+        assert_eq!(FontPalette::Custom("--brand".into()).into_css(), "--brand");
+        assert_eq!(FontLanguageOverride::from("TRK").into_css(), "\"TRK\"");
+
+        let block = css!{
+            font_synthesis: FontSynthesis::ALL;
+            font_size_adjust: FontSizeAdjust::Metric(FontMetric::ExHeight, Some(0.5));
+            font_palette: FontPalette::Dark;
+            font_language_override: FontLanguageOverride::Normal;
+            font_variant: FontVariant::None;
+            font_variant_alternates: FontVariantAlternates::Swash("fancy".into());
+            font_variant_east_asian: FontVariantEastAsian::Ruby;
+        };
+        let css = block.to_string();
+        for declaration in [
+            "font-synthesis: weight style small-caps position;",
+            "font-size-adjust: ex-height 0.5;",
+            "font-palette: dark;",
+            "font-language-override: normal;",
+            "font-variant: none;",
+            "font-variant-alternates: swash(fancy);",
+            "font-variant-east-asian: ruby;",
+        ] {
+            assert!(css.contains(declaration), "{} not in {}", declaration, css);
+        }
+    }
+}
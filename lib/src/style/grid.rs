@@ -0,0 +1,654 @@
+use std::{
+    convert::TryFrom,
+    fmt::Display,
+    num::{NonZeroI32, NonZeroU32},
+};
+
+use crate::css_enum;
+
+use super::{quantities::Length, IntoCss};
+
+css_enum!(GridAutoFlow; Row | Column | Dense | RowDense "row dense" | ColumnDense "column dense");
+
+/// The size of a row or column
+#[derive(Clone)]
+pub enum TrackSize {
+    Length(Length),
+    /// A fraction of the free space
+    Fr(f64),
+    Auto,
+    MinContent,
+    MaxContent,
+    /// `minmax(min, max)`
+    MinMax(Box<TrackSize>, Box<TrackSize>),
+    /// `fit-content(limit)`
+    FitContent(Length),
+}
+
+impl TrackSize {
+    /// `minmax(min, max)`, `None` if `min` is a fraction since the minimum can not be flexible
+    pub fn minmax(min: impl Into<TrackSize>, max: impl Into<TrackSize>) -> Option<Self> {
+        match min.into() {
+            Self::Fr(_) => None,
+            min => Some(Self::MinMax(Box::new(min), Box::new(max.into()))),
+        }
+    }
+}
+
+impl TrackSize {
+    /// Whether the size does not depend on the content or the free space, which a list with `repeat(auto-fill, ...)` needs
+    fn is_fixed(&self) -> bool {
+        match self {
+            Self::Length(_) => true,
+            Self::MinMax(min, max) => matches!(**min, Self::Length(_)) || matches!(**max, Self::Length(_)),
+            Self::Fr(_) | Self::Auto | Self::MinContent | Self::MaxContent | Self::FitContent(_) => false,
+        }
+    }
+}
+
+impl IntoCss for TrackSize {
+    fn into_css(self) -> String {
+        match self {
+            Self::Length(length) => length.into_css(),
+            Self::Fr(fr) => format!("{fr}fr"),
+            Self::Auto => "auto".to_string(),
+            Self::MinContent => "min-content".to_string(),
+            Self::MaxContent => "max-content".to_string(),
+            Self::MinMax(min, max) => format!("minmax({}, {})", min.into_css(), max.into_css()),
+            Self::FitContent(limit) => format!("fit-content({})", limit.into_css()),
+        }
+    }
+}
+
+impl From<Length> for TrackSize {
+    fn from(length: Length) -> Self {
+        Self::Length(length)
+    }
+}
+
+impl From<i64> for TrackSize {
+    fn from(size: i64) -> Self {
+        Self::Length(size.into())
+    }
+}
+
+impl From<f64> for TrackSize {
+    fn from(size: f64) -> Self {
+        Self::Length(size.into())
+    }
+}
+
+/// How often the tracks of `repeat()` are repeated
+#[derive(Clone, Copy, Debug)]
+pub enum RepeatCount {
+    Count(u32),
+    /// As many as fit into the container, empty tracks are kept
+    AutoFill,
+    /// As many as fit into the container, empty tracks collapse
+    AutoFit,
+}
+
+impl IntoCss for RepeatCount {
+    fn into_css(self) -> String {
+        match self {
+            Self::Count(count) => count.to_string(),
+            Self::AutoFill => "auto-fill".to_string(),
+            Self::AutoFit => "auto-fit".to_string(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum TrackListItem {
+    Track(TrackSize),
+    /// Names for the line between two tracks, e.g. `[content-start main]`
+    Lines(Vec<String>),
+    Repeat(RepeatCount, TrackList),
+}
+
+impl IntoCss for TrackListItem {
+    fn into_css(self) -> String {
+        match self {
+            Self::Track(size) => size.into_css(),
+            Self::Lines(names) => format!("[{}]", names.join(" ")),
+            Self::Repeat(count, tracks) => format!("repeat({}, {})", count.into_css(), tracks.into_css()),
+        }
+    }
+}
+
+/// The tracks of `grid-template-columns` and `grid-template-rows` with named lines, `none` if it is empty
+#[derive(Clone, Default)]
+pub struct TrackList(pub Vec<TrackListItem>);
+
+impl TrackList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Panics if the size is not fixed and the list has an `auto-fill` or `auto-fit` repetition, see `TrackList::try_track`
+    pub fn track(self, size: impl Into<TrackSize>) -> Self {
+        self.try_track(size).expect("a track list with auto-fill or auto-fit can only have fixed track sizes")
+    }
+
+    /// Adds the track, or returns `TrackListError::FlexibleAutoRepeat` if the size is not fixed and the list has an `auto-fill` or `auto-fit` repetition
+    pub fn try_track(mut self, size: impl Into<TrackSize>) -> Result<Self, TrackListError> {
+        let size = size.into();
+        if self.has_auto_repeat() && !size.is_fixed() {
+            return Err(TrackListError::FlexibleAutoRepeat);
+        }
+        self.0.push(TrackListItem::Track(size));
+        Ok(self)
+    }
+
+    /// Names the next line, several names separated by whitespace name the same line
+    pub fn line(mut self, names: &str) -> Self {
+        self.0.push(TrackListItem::Lines(names.split_whitespace().map(str::to_string).collect()));
+        self
+    }
+
+    /// `repeat(count, tracks)`, see `TrackListError` for the repetitions that are rejected
+    pub fn repeat(mut self, count: RepeatCount, tracks: impl Into<TrackList>) -> Result<Self, TrackListError> {
+        let tracks = tracks.into();
+        if tracks.0.iter().any(|item| matches!(item, TrackListItem::Repeat(..))) {
+            return Err(TrackListError::NestedRepeat);
+        }
+        if !tracks.0.iter().any(|item| matches!(item, TrackListItem::Track(_))) {
+            return Err(TrackListError::EmptyRepeat);
+        }
+        match count {
+            RepeatCount::Count(0) => return Err(TrackListError::ZeroRepeat),
+            RepeatCount::Count(_) => {
+                if self.has_auto_repeat() && !tracks.is_fixed() {
+                    return Err(TrackListError::FlexibleAutoRepeat);
+                }
+            },
+            RepeatCount::AutoFill | RepeatCount::AutoFit => {
+                if self.has_auto_repeat() {
+                    return Err(TrackListError::SecondAutoRepeat);
+                }
+                // the other tracks of the list have to be fixed as well
+                if !tracks.is_fixed() || !self.is_fixed() {
+                    return Err(TrackListError::FlexibleAutoRepeat);
+                }
+            },
+        }
+        self.0.push(TrackListItem::Repeat(count, tracks));
+        Ok(self)
+    }
+
+    fn has_auto_repeat(&self) -> bool {
+        self.0.iter().any(|item| matches!(item, TrackListItem::Repeat(RepeatCount::AutoFill | RepeatCount::AutoFit, _)))
+    }
+
+    /// Whether every track, also the repeated ones, is fixed
+    fn is_fixed(&self) -> bool {
+        self.0.iter().all(|item| match item {
+            TrackListItem::Track(size) => size.is_fixed(),
+            TrackListItem::Lines(_) => true,
+            TrackListItem::Repeat(_, tracks) => tracks.is_fixed(),
+        })
+    }
+}
+
+impl IntoCss for TrackList {
+    fn into_css(self) -> String {
+        if self.0.is_empty() {
+            return "none".to_string();
+        }
+        self.0.into_iter().map(TrackListItem::into_css).collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl From<TrackSize> for TrackList {
+    fn from(size: TrackSize) -> Self {
+        Self(vec![TrackListItem::Track(size)])
+    }
+}
+
+impl From<Vec<TrackSize>> for TrackList {
+    fn from(sizes: Vec<TrackSize>) -> Self {
+        Self(sizes.into_iter().map(TrackListItem::Track).collect())
+    }
+}
+
+/// The `repeat()`s that `TrackList::repeat` rejects
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrackListError {
+    /// The repeated list has no track
+    EmptyRepeat,
+    /// The tracks are repeated 0 times
+    ZeroRepeat,
+    /// The repeated list contains a `repeat()`
+    NestedRepeat,
+    /// A list with `auto-fill` or `auto-fit` and a size that depends on the content or the free space (`fr`, `auto`, `min-content`, ...),
+    /// in the repetition or anywhere else in the list
+    FlexibleAutoRepeat,
+    /// A second `auto-fill` or `auto-fit` repetition in the same list
+    SecondAutoRepeat,
+}
+
+impl Display for TrackListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyRepeat => write!(f, "repeat() without tracks"),
+            Self::ZeroRepeat => write!(f, "repeat() with a count of 0"),
+            Self::NestedRepeat => write!(f, "repeat() inside of repeat()"),
+            Self::FlexibleAutoRepeat => write!(f, "a track list with auto-fill or auto-fit can only have fixed track sizes"),
+            Self::SecondAutoRepeat => write!(f, "a track list can only have one auto-fill or auto-fit repetition"),
+        }
+    }
+}
+
+impl std::error::Error for TrackListError {}
+
+/// A line that an item starts or ends at, there is no line 0
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridLine {
+    Auto,
+    /// The line with the number, negative numbers count from the end
+    Line(NonZeroI32),
+    /// The line with the name, or the nth line with the name
+    Named(String, Option<NonZeroI32>),
+    /// Spans the number of tracks
+    Span(NonZeroU32),
+    /// Spans until the next line with the name
+    SpanNamed(String),
+}
+
+impl GridLine {
+    /// The line with the number, `None` for 0 and numbers outside of the `i32` range
+    pub fn line(line: i64) -> Option<Self> {
+        i32::try_from(line).ok().and_then(NonZeroI32::new).map(Self::Line)
+    }
+
+    /// Spans `tracks` tracks, `None` for 0
+    pub fn span(tracks: u32) -> Option<Self> {
+        NonZeroU32::new(tracks).map(Self::Span)
+    }
+}
+
+impl IntoCss for GridLine {
+    fn into_css(self) -> String {
+        match self {
+            Self::Auto => "auto".to_string(),
+            Self::Line(line) => line.to_string(),
+            Self::Named(name, Some(n)) => format!("{name} {n}"),
+            Self::Named(name, None) => name,
+            Self::Span(span) => format!("span {span}"),
+            Self::SpanNamed(name) => format!("span {name}"),
+        }
+    }
+}
+
+/// # Panics
+/// For 0 and numbers outside of the `i32` range, `GridLine::line` returns `None` instead
+impl From<i64> for GridLine {
+    fn from(line: i64) -> Self {
+        Self::line(line).expect("grid lines are numbered from 1 (or -1) within the i32 range")
+    }
+}
+
+impl From<&str> for GridLine {
+    fn from(name: &str) -> Self {
+        Self::Named(name.to_string(), None)
+    }
+}
+
+/// The placement of `grid-column` and `grid-row`, e.g. `1 / span 2`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridPlacement {
+    pub start: GridLine,
+    pub end: Option<GridLine>,
+}
+
+impl GridPlacement {
+    pub fn new(start: impl Into<GridLine>, end: impl Into<GridLine>) -> Self {
+        Self { start: start.into(), end: Some(end.into()) }
+    }
+}
+
+impl IntoCss for GridPlacement {
+    fn into_css(self) -> String {
+        match self.end {
+            Some(end) => format!("{} / {}", self.start.into_css(), end.into_css()),
+            None => self.start.into_css(),
+        }
+    }
+}
+
+impl<T: Into<GridLine>> From<T> for GridPlacement {
+    fn from(start: T) -> Self {
+        Self { start: start.into(), end: None }
+    }
+}
+
+/// `grid-area`, a named area or up to four lines (row start, column start, row end, column end)
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridArea(pub Vec<GridLine>);
+
+impl IntoCss for GridArea {
+    fn into_css(self) -> String {
+        self.0.into_iter().map(GridLine::into_css).collect::<Vec<_>>().join(" / ")
+    }
+}
+
+impl From<&str> for GridArea {
+    fn from(name: &str) -> Self {
+        Self(vec![name.into()])
+    }
+}
+
+/// The named areas of `grid-template-areas`, every area is a filled rectangle
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridTemplateAreas {
+    /// The cells of each row, `None` for cells without an area (`.`)
+    rows: Vec<Vec<Option<String>>>,
+}
+
+impl GridTemplateAreas {
+    /// Parses the rows, e.g. `["header header", "sidebar main", ". footer"]`
+    pub fn new(rows: &[&str]) -> Result<Self, GridAreasError> {
+        if rows.is_empty() {
+            return Err(GridAreasError::Empty);
+        }
+        let mut cells = Vec::new();
+        for row in rows {
+            let mut row_cells = Vec::new();
+            for cell in row.split_whitespace() {
+                if cell.chars().all(|c| c == '.') {
+                    row_cells.push(None);
+                } else if cell.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()) {
+                    row_cells.push(Some(cell.to_string()));
+                } else {
+                    return Err(GridAreasError::InvalidName(cell.to_string()));
+                }
+            }
+            cells.push(row_cells);
+        }
+
+        let columns = cells[0].len();
+        if columns == 0 {
+            return Err(GridAreasError::Empty);
+        }
+        if let Some((row, found)) = cells.iter().map(Vec::len).enumerate().find(|(_, len)| *len != columns) {
+            return Err(GridAreasError::RowLength { row, expected: columns, found });
+        }
+
+        // every cell in the bounding box of an area has to belong to it
+        let mut checked: Vec<&str> = Vec::new();
+        for name in cells.iter().flatten().flatten() {
+            if checked.contains(&name.as_str()) {
+                continue;
+            }
+            checked.push(name);
+            let positions: Vec<(usize, usize)> = cells.iter().enumerate()
+                .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, cell)| cell.as_ref() == Some(name)).map(move |(x, _)| (y, x)))
+                .collect();
+            let (top, bottom) = (positions.iter().map(|p| p.0).min().unwrap_or(0), positions.iter().map(|p| p.0).max().unwrap_or(0));
+            let (left, right) = (positions.iter().map(|p| p.1).min().unwrap_or(0), positions.iter().map(|p| p.1).max().unwrap_or(0));
+            if positions.len() != (bottom - top + 1) * (right - left + 1) {
+                return Err(GridAreasError::NotRectangular(name.clone()));
+            }
+        }
+        Ok(Self { rows: cells })
+    }
+
+    /// The names of the areas in the order they first appear
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for name in self.rows.iter().flatten().flatten() {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Each row as a quoted string
+    fn row_strings(self) -> Vec<String> {
+        self.rows.into_iter().map(|row| {
+            let cells: Vec<String> = row.into_iter().map(|cell| cell.unwrap_or_else(|| ".".to_string())).collect();
+            format!("\"{}\"", cells.join(" "))
+        }).collect()
+    }
+}
+
+impl IntoCss for GridTemplateAreas {
+    fn into_css(self) -> String {
+        self.row_strings().join(" ")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridAreasError {
+    /// There are no rows or cells
+    Empty,
+    /// A row has a different number of cells than the first one
+    RowLength { row: usize, expected: usize, found: usize },
+    /// A cell is neither an identifier nor `.`
+    InvalidName(String),
+    /// The cells of the area do not form a filled rectangle
+    NotRectangular(String),
+}
+
+impl Display for GridAreasError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid template areas without cells"),
+            Self::RowLength { row, expected, found } => write!(f, "row {row} has {found} cells instead of {expected}"),
+            Self::InvalidName(name) => write!(f, "invalid area name `{name}`"),
+            Self::NotRectangular(name) => write!(f, "the area `{name}` is not a rectangle"),
+        }
+    }
+}
+
+impl std::error::Error for GridAreasError {}
+
+/// The `grid-template` shorthand
+#[derive(Clone)]
+pub enum GridTemplate {
+    None,
+    /// `rows / columns`
+    Tracks(TrackList, TrackList),
+    /// Named areas with an optional size for each row and the columns, e.g. `"a a" 100px "b c" 1fr / 200px 1fr`
+    Areas { areas: GridTemplateAreas, row_sizes: Vec<TrackSize>, columns: Option<TrackList> },
+}
+
+impl GridTemplate {
+    /// `rows / columns`
+    pub fn tracks(rows: impl Into<TrackList>, columns: impl Into<TrackList>) -> Self {
+        Self::Tracks(rows.into(), columns.into())
+    }
+
+    /// The areas with the sizes of their rows (none or one per row) and the columns,
+    /// `None` if the number of sizes does not match or the columns contain `repeat()`, which the shorthand does not allow here
+    pub fn areas(areas: GridTemplateAreas, row_sizes: Vec<TrackSize>, columns: Option<TrackList>) -> Option<Self> {
+        let repeats = columns.iter().flat_map(|columns| &columns.0).any(|item| matches!(item, TrackListItem::Repeat(..)));
+        if (!row_sizes.is_empty() && row_sizes.len() != areas.rows.len()) || repeats {
+            return None;
+        }
+        Some(Self::Areas { areas, row_sizes, columns })
+    }
+}
+
+impl IntoCss for GridTemplate {
+    fn into_css(self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::Tracks(rows, columns) => format!("{} / {}", rows.into_css(), columns.into_css()),
+            Self::Areas { areas, row_sizes, columns } => {
+                let mut sizes = row_sizes.into_iter();
+                let rows: Vec<String> = areas.row_strings().into_iter().map(|row| match sizes.next() {
+                    Some(size) => format!("{row} {}", size.into_css()),
+                    None => row,
+                }).collect();
+                match columns {
+                    Some(columns) => format!("{} / {}", rows.join(" "), columns.into_css()),
+                    None => rows.join(" "),
+                }
+            },
+        }
+    }
+}
+
+impl From<GridTemplateAreas> for GridTemplate {
+    fn from(areas: GridTemplateAreas) -> Self {
+        Self::Areas { areas, row_sizes: Vec::new(), columns: None }
+    }
+}
+
+/// The `grid` shorthand, an explicit template or one axis with implicitly created tracks
+#[derive(Clone)]
+pub enum Grid {
+    Template(GridTemplate),
+    /// `rows / auto-flow columns`, items fill new columns of the size `columns`
+    AutoColumns { rows: TrackList, dense: bool, columns: Option<TrackSize> },
+    /// `auto-flow rows / columns`, items fill new rows of the size `rows`
+    AutoRows { dense: bool, rows: Option<TrackSize>, columns: TrackList },
+}
+
+impl IntoCss for Grid {
+    fn into_css(self) -> String {
+        let auto_flow = |dense: bool, size: Option<TrackSize>| {
+            let mut parts = vec!["auto-flow".to_string()];
+            if dense {
+                parts.push("dense".to_string());
+            }
+            parts.extend(size.map(TrackSize::into_css));
+            parts.join(" ")
+        };
+        match self {
+            Self::Template(template) => template.into_css(),
+            Self::AutoColumns { rows, dense, columns } => format!("{} / {}", rows.into_css(), auto_flow(dense, columns)),
+            Self::AutoRows { dense, rows, columns } => format!("{} / {}", auto_flow(dense, rows), columns.into_css()),
+        }
+    }
+}
+
+impl From<GridTemplate> for Grid {
+    fn from(template: GridTemplate) -> Self {
+        Self::Template(template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css;
+
+    use super::*;
+
+    fn fr(fr: f64) -> TrackSize {
+        TrackSize::Fr(fr)
+    }
+
+    #[test]
+    fn track_lists() {
+        let columns = TrackList::new()
+            .line("full-start").track(TrackSize::minmax(Length::Rem(1.0), fr(1.0)).unwrap())
+            .line("content-start").repeat(RepeatCount::AutoFill, TrackSize::minmax(200, fr(1.0)).unwrap()).unwrap()
+            .line("content-end");
+        assert_eq!(
+            columns.into_css(),
+            "[full-start] minmax(1rem, 1fr) [content-start] repeat(auto-fill, minmax(200px, 1fr)) [content-end]",
+        );
+        assert_eq!(TrackList::new().into_css(), "none");
+        assert!(TrackSize::minmax(fr(1.0), 100).is_none());
+        assert_eq!(TrackList::from(vec![fr(1.0), TrackSize::FitContent(Length::Px(300.0))]).into_css(), "1fr fit-content(300px)");
+    }
+
+    #[test]
+    fn repeat_is_validated() {
+        let list = TrackList::new();
+        assert!(list.clone().repeat(RepeatCount::Count(3), fr(1.0)).is_ok());
+        assert_eq!(list.clone().repeat(RepeatCount::Count(0), fr(1.0)).err(), Some(TrackListError::ZeroRepeat));
+        assert_eq!(list.clone().repeat(RepeatCount::Count(2), TrackList::new().line("a")).err(), Some(TrackListError::EmptyRepeat));
+        for size in [fr(1.0), TrackSize::Auto, TrackSize::MinContent, TrackSize::MaxContent, TrackSize::FitContent(Length::Px(10.0))] {
+            assert_eq!(list.clone().repeat(RepeatCount::AutoFit, size).err(), Some(TrackListError::FlexibleAutoRepeat));
+        }
+        assert!(list.clone().repeat(RepeatCount::AutoFit, TrackSize::minmax(TrackSize::MinContent, 100).unwrap()).is_ok());
+        assert!(list.clone().repeat(RepeatCount::AutoFit, TrackSize::minmax(TrackSize::Auto, fr(1.0)).unwrap()).is_err());
+
+        let inner = TrackList::new().repeat(RepeatCount::Count(2), fr(1.0)).unwrap();
+        assert_eq!(list.clone().repeat(RepeatCount::Count(2), inner).err(), Some(TrackListError::NestedRepeat));
+        let auto = list.repeat(RepeatCount::AutoFill, TrackSize::from(100)).unwrap();
+        assert_eq!(auto.repeat(RepeatCount::AutoFit, TrackSize::from(100)).err(), Some(TrackListError::SecondAutoRepeat));
+    }
+
+    #[test]
+    fn auto_repeat_needs_a_fixed_list() {
+        // a flexible track before the auto repetition
+        let flexible = TrackList::new().track(fr(1.0));
+        assert_eq!(flexible.repeat(RepeatCount::AutoFill, TrackSize::from(100)).err(), Some(TrackListError::FlexibleAutoRepeat));
+        let repeated = TrackList::new().repeat(RepeatCount::Count(2), TrackSize::Auto).unwrap();
+        assert_eq!(repeated.repeat(RepeatCount::AutoFit, TrackSize::from(100)).err(), Some(TrackListError::FlexibleAutoRepeat));
+
+        // and after it
+        let auto = TrackList::new().track(100).repeat(RepeatCount::AutoFill, TrackSize::from(100)).unwrap();
+        assert_eq!(auto.clone().try_track(fr(1.0)).err(), Some(TrackListError::FlexibleAutoRepeat));
+        assert_eq!(auto.clone().repeat(RepeatCount::Count(2), fr(1.0)).err(), Some(TrackListError::FlexibleAutoRepeat));
+        let fixed = auto.line("end").track(TrackSize::minmax(100, fr(1.0)).unwrap()).repeat(RepeatCount::Count(2), TrackSize::from(50)).unwrap();
+        assert_eq!(fixed.into_css(), "100px repeat(auto-fill, 100px) [end] minmax(100px, 1fr) repeat(2, 50px)");
+    }
+
+    #[test]
+    #[should_panic]
+    fn flexible_track_after_auto_repeat_panics() {
+        TrackList::new().repeat(RepeatCount::AutoFill, TrackSize::from(100)).unwrap().track(TrackSize::Auto);
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(GridLine::line(0), None);
+        assert_eq!(GridLine::line(i64::from(i32::MAX) + 1), None);
+        assert_eq!(GridLine::span(0), None);
+        assert_eq!(GridPlacement::new(1, GridLine::span(2).unwrap()).into_css(), "1 / span 2");
+        assert_eq!(GridPlacement::from(-1).into_css(), "-1");
+        assert_eq!(GridLine::Named("content".into(), NonZeroI32::new(2)).into_css(), "content 2");
+        assert_eq!(GridArea(vec![1.into(), "main".into(), GridLine::SpanNamed("end".into())]).into_css(), "1 / main / span end");
+    }
+
+    #[test]
+    #[should_panic]
+    fn line_zero_panics() {
+        let _ = GridLine::from(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lines_are_not_truncated() {
+        let _ = GridLine::from(1 << 32);
+    }
+
+    #[test]
+    fn template_areas() {
+        let areas = GridTemplateAreas::new(&["header header", "sidebar main", ". footer"]).unwrap();
+        assert_eq!(areas.names(), ["header", "sidebar", "main", "footer"]);
+        assert_eq!(areas.into_css(), "\"header header\" \"sidebar main\" \". footer\"");
+        assert_eq!(GridTemplateAreas::new(&[]), Err(GridAreasError::Empty));
+        assert_eq!(GridTemplateAreas::new(&["a b", "c"]), Err(GridAreasError::RowLength { row: 1, expected: 2, found: 1 }));
+        assert_eq!(GridTemplateAreas::new(&["a b", "b a"]), Err(GridAreasError::NotRectangular("a".into())));
+        assert_eq!(GridTemplateAreas::new(&["a a", "a ."]), Err(GridAreasError::NotRectangular("a".into())));
+        assert_eq!(GridTemplateAreas::new(&["a \"b\""]), Err(GridAreasError::InvalidName("\"b\"".into())));
+    }
+
+    #[test]
+    fn shorthands() {
+        let areas = GridTemplateAreas::new(&["a a", "b c"]).unwrap();
+        let template = GridTemplate::areas(areas.clone(), vec![100.into(), fr(1.0)], Some(vec![200.into(), fr(1.0)].into())).unwrap();
+        assert_eq!(template.clone().into_css(), "\"a a\" 100px \"b c\" 1fr / 200px 1fr");
+        assert!(GridTemplate::areas(areas.clone(), vec![100.into()], None).is_none());
+        let repeated = TrackList::new().repeat(RepeatCount::Count(2), fr(1.0)).unwrap();
+        assert!(GridTemplate::areas(areas.clone(), Vec::new(), Some(repeated)).is_none());
+        assert_eq!(GridTemplate::from(areas).into_css(), "\"a a\" \"b c\"");
+        assert_eq!(GridTemplate::tracks(fr(1.0), vec![100.into(), fr(1.0)]).into_css(), "1fr / 100px 1fr");
+
+        let grid = Grid::AutoRows { dense: true, rows: Some(100.into()), columns: TrackList::from(vec![fr(1.0), fr(2.0)]) };
+        assert_eq!(grid.into_css(), "auto-flow dense 100px / 1fr 2fr");
+        assert_eq!(Grid::AutoColumns { rows: fr(1.0).into(), dense: false, columns: None }.into_css(), "1fr / auto-flow");
+
+        let block = css!{ grid: Grid::from(template); grid_template: GridTemplate::None; };
+        let css = block.to_string();
+        assert!(css.contains("grid: \"a a\" 100px \"b c\" 1fr / 200px 1fr;"), "{}", css);
+        assert!(css.contains("grid-template: none;"), "{}", css);
+    }
+}
@@ -1,4 +1,6 @@
-use super::{url::Url, IntoCss};
+use crate::css_enum;
+
+use super::{url::Url, color::{Color, NamedColor}, quantities::{Length, Angle}, IntoCss};
 
 #[derive(Clone)]
 pub enum Image {
@@ -21,13 +23,388 @@ impl From<url::Url> for Image {
     }
 }
 
+impl From<Gradient> for Image {
+    fn from(g: Gradient) -> Self {
+        Self::Gradient(g)
+    }
+}
+
+impl From<LinearGradient> for Image {
+    fn from(g: LinearGradient) -> Self {
+        Self::Gradient(g.into())
+    }
+}
+
+impl From<RadialGradient> for Image {
+    fn from(g: RadialGradient) -> Self {
+        Self::Gradient(g.into())
+    }
+}
+
+impl From<ConicGradient> for Image {
+    fn from(g: ConicGradient) -> Self {
+        Self::Gradient(g.into())
+    }
+}
+
 #[derive(Clone)]
 pub enum Gradient {
-
+    /// `linear-gradient()`
+    Linear(LinearGradient),
+    /// `repeating-linear-gradient()`
+    RepeatingLinear(LinearGradient),
+    /// `radial-gradient()`
+    Radial(RadialGradient),
+    /// `repeating-radial-gradient()`
+    RepeatingRadial(RadialGradient),
+    /// `conic-gradient()`
+    Conic(ConicGradient),
+    /// `repeating-conic-gradient()`
+    RepeatingConic(ConicGradient),
 }
 
 impl IntoCss for Gradient {
     fn into_css(self) -> String {
-        todo!()
+        match self {
+            Self::Linear(g) => format!("linear-gradient({})", g.into_css()),
+            Self::RepeatingLinear(g) => format!("repeating-linear-gradient({})", g.into_css()),
+            Self::Radial(g) => format!("radial-gradient({})", g.into_css()),
+            Self::RepeatingRadial(g) => format!("repeating-radial-gradient({})", g.into_css()),
+            Self::Conic(g) => format!("conic-gradient({})", g.into_css()),
+            Self::RepeatingConic(g) => format!("repeating-conic-gradient({})", g.into_css()),
+        }
+    }
+}
+
+impl From<LinearGradient> for Gradient {
+    fn from(g: LinearGradient) -> Self {
+        Self::Linear(g)
+    }
+}
+
+impl From<RadialGradient> for Gradient {
+    fn from(g: RadialGradient) -> Self {
+        Self::Radial(g)
+    }
+}
+
+impl From<ConicGradient> for Gradient {
+    fn from(g: ConicGradient) -> Self {
+        Self::Conic(g)
+    }
+}
+
+/// Joins the optional gradient prelude (direction, shape, position...) and the color stops
+fn gradient_arguments<P: IntoCss>(prelude: Vec<String>, stops: Vec<ColorStop<P>>) -> String {
+    let stops = stops.into_iter().map(IntoCss::into_css).collect::<Vec<_>>().join(", ");
+    if prelude.is_empty() {
+        stops
+    } else {
+        format!("{}, {}", prelude.join(" "), stops)
+    }
+}
+
+/// The arguments of a (repeating) `linear-gradient()`.
+/// 
+/// A missing `direction` defaults to `to bottom`.
+#[derive(Clone)]
+pub struct LinearGradient {
+    pub direction: Option<LinearDirection>,
+    pub stops: Vec<ColorStop<Length>>,
+}
+
+impl LinearGradient {
+    pub fn new(direction: impl Into<LinearDirection>, stops: Vec<ColorStop<Length>>) -> Self {
+        Self { direction: Some(direction.into()), stops }
+    }
+}
+
+impl IntoCss for LinearGradient {
+    fn into_css(self) -> String {
+        let prelude = self.direction.into_iter().map(IntoCss::into_css).collect();
+        gradient_arguments(prelude, self.stops)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum LinearDirection {
+    Angle(Angle),
+    To(SideOrCorner),
+}
+
+impl IntoCss for LinearDirection {
+    fn into_css(self) -> String {
+        match self {
+            Self::Angle(angle) => angle.into_css(),
+            Self::To(side) => format!("to {}", side.into_css()),
+        }
+    }
+}
+
+impl From<Angle> for LinearDirection {
+    fn from(angle: Angle) -> Self {
+        Self::Angle(angle)
+    }
+}
+
+impl From<SideOrCorner> for LinearDirection {
+    fn from(side: SideOrCorner) -> Self {
+        Self::To(side)
+    }
+}
+
+css_enum!(SideOrCorner; 
+    Top | Right | Bottom | Left | 
+    TopLeft "top left" | TopRight "top right" | BottomLeft "bottom left" | BottomRight "bottom right"
+);
+
+/// The arguments of a (repeating) `radial-gradient()`.
+/// 
+/// Missing fields are left out and fall back to `ellipse farthest-corner at center`.
+#[derive(Clone, Default)]
+pub struct RadialGradient {
+    pub shape: Option<RadialShape>,
+    pub size: Option<RadialSize>,
+    pub position: Option<ImagePosition>,
+    pub stops: Vec<ColorStop<Length>>,
+}
+
+impl RadialGradient {
+    pub fn new(stops: Vec<ColorStop<Length>>) -> Self {
+        Self { stops, ..Default::default() }
+    }
+}
+
+impl IntoCss for RadialGradient {
+    fn into_css(self) -> String {
+        let mut prelude = Vec::new();
+        if let Some(shape) = self.shape {
+            prelude.push(shape.into_css());
+        }
+        if let Some(size) = self.size {
+            prelude.push(size.into_css());
+        }
+        if let Some(position) = self.position {
+            prelude.push(format!("at {}", position.into_css()));
+        }
+        gradient_arguments(prelude, self.stops)
+    }
+}
+
+css_enum!(RadialShape; Circle | Ellipse);
+
+#[derive(Clone)]
+pub enum RadialSize {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+    /// Radius of a circle
+    Radius(Length),
+    /// Horizontal and vertical radius of an ellipse
+    Radii(Length, Length),
+}
+
+impl IntoCss for RadialSize {
+    fn into_css(self) -> String {
+        match self {
+            Self::ClosestSide => "closest-side".to_string(),
+            Self::ClosestCorner => "closest-corner".to_string(),
+            Self::FarthestSide => "farthest-side".to_string(),
+            Self::FarthestCorner => "farthest-corner".to_string(),
+            Self::Radius(r) => r.into_css(),
+            Self::Radii(x, y) => format!("{} {}", x.into_css(), y.into_css()),
+        }
+    }
+}
+
+/// The arguments of a (repeating) `conic-gradient()`.
+/// 
+/// Color stops of a conic gradient are angles, a percentage of the full circle can be expressed as `Angle::Turn`.
+#[derive(Clone, Default)]
+pub struct ConicGradient {
+    pub from: Option<Angle>,
+    pub position: Option<ImagePosition>,
+    pub stops: Vec<ColorStop<Angle>>,
+}
+
+impl ConicGradient {
+    pub fn new(stops: Vec<ColorStop<Angle>>) -> Self {
+        Self { stops, ..Default::default() }
+    }
+}
+
+impl IntoCss for ConicGradient {
+    fn into_css(self) -> String {
+        let mut prelude = Vec::new();
+        if let Some(from) = self.from {
+            prelude.push(format!("from {}", from.into_css()));
+        }
+        if let Some(position) = self.position {
+            prelude.push(format!("at {}", position.into_css()));
+        }
+        gradient_arguments(prelude, self.stops)
+    }
+}
+
+/// A `<position>` made of a horizontal and a vertical component
+#[derive(Clone)]
+pub struct ImagePosition(pub PositionComponent, pub PositionComponent);
+
+impl IntoCss for ImagePosition {
+    fn into_css(self) -> String {
+        format!("{} {}", self.0.into_css(), self.1.into_css())
+    }
+}
+
+impl<X: Into<PositionComponent>, Y: Into<PositionComponent>> From<(X, Y)> for ImagePosition {
+    fn from((x, y): (X, Y)) -> Self {
+        Self(x.into(), y.into())
+    }
+}
+
+impl From<PositionKeyword> for ImagePosition {
+    fn from(keyword: PositionKeyword) -> Self {
+        Self(keyword.into(), PositionKeyword::Center.into())
+    }
+}
+
+#[derive(Clone)]
+pub enum PositionComponent {
+    Keyword(PositionKeyword),
+    Length(Length),
+}
+
+impl IntoCss for PositionComponent {
+    fn into_css(self) -> String {
+        match self {
+            Self::Keyword(k) => k.into_css(),
+            Self::Length(l) => l.into_css(),
+        }
+    }
+}
+
+impl From<PositionKeyword> for PositionComponent {
+    fn from(keyword: PositionKeyword) -> Self {
+        Self::Keyword(keyword)
+    }
+}
+
+impl From<Length> for PositionComponent {
+    fn from(length: Length) -> Self {
+        Self::Length(length)
+    }
+}
+
+impl From<i64> for PositionComponent {
+    fn from(length: i64) -> Self {
+        Self::Length(length.into())
+    }
+}
+
+impl From<f64> for PositionComponent {
+    fn from(length: f64) -> Self {
+        Self::Length(length.into())
     }
-}
\ No newline at end of file
+}
+
+css_enum!(PositionKeyword; Center | Top | Right | Bottom | Left);
+
+/// A color stop or color hint inside a gradient.
+/// 
+/// `P` is the type of the stop position, `Length` for linear and radial gradients and `Angle` for conic gradients.
+#[derive(Clone)]
+pub enum ColorStop<P> {
+    /// A color without position, it is placed halfway between its neighbours
+    Color(Color),
+    /// A color at a fixed position
+    At(Color, P),
+    /// A color spanning from the first to the second position
+    Between(Color, P, P),
+    /// A transition hint between two color stops
+    Hint(P),
+}
+
+impl<P: IntoCss> IntoCss for ColorStop<P> {
+    fn into_css(self) -> String {
+        match self {
+            Self::Color(color) => color.into_css(),
+            Self::At(color, at) => format!("{} {}", color.into_css(), at.into_css()),
+            Self::Between(color, start, end) => format!("{} {} {}", color.into_css(), start.into_css(), end.into_css()),
+            Self::Hint(at) => at.into_css(),
+        }
+    }
+}
+
+impl<P> From<Color> for ColorStop<P> {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl<P> From<NamedColor> for ColorStop<P> {
+    fn from(color: NamedColor) -> Self {
+        Self::Color(color.into())
+    }
+}
+
+impl<P, X: Into<P>> From<(Color, X)> for ColorStop<P> {
+    fn from((color, at): (Color, X)) -> Self {
+        Self::At(color, at.into())
+    }
+}
+
+impl<P, X: Into<P>> From<(NamedColor, X)> for ColorStop<P> {
+    fn from((color, at): (NamedColor, X)) -> Self {
+        Self::At(color.into(), at.into())
+    }
+}
+
+impl<P, X: Into<P>, Y: Into<P>> From<(Color, X, Y)> for ColorStop<P> {
+    fn from((color, start, end): (Color, X, Y)) -> Self {
+        Self::Between(color, start.into(), end.into())
+    }
+}
+
+impl<P, X: Into<P>, Y: Into<P>> From<(NamedColor, X, Y)> for ColorStop<P> {
+    fn from((color, start, end): (NamedColor, X, Y)) -> Self {
+        Self::Between(color.into(), start.into(), end.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_gradient() {
+        let gradient = LinearGradient::new(SideOrCorner::TopRight, vec![
+            NamedColor::Red.into(),
+            ColorStop::Hint(Length::Percent(30.0)),
+            (NamedColor::Blue, Length::Px(40.0), Length::Px(60.0)).into(),
+        ]);
+        assert_eq!(Image::from(gradient).into_css(), "linear-gradient(to top right, red, 30%, blue 40px 60px)");
+
+        let without_direction = LinearGradient { direction: None, stops: vec![NamedColor::Red.into(), NamedColor::Blue.into()] };
+        assert_eq!(Gradient::RepeatingLinear(without_direction).into_css(), "repeating-linear-gradient(red, blue)");
+    }
+
+    #[test]
+    fn radial_gradient() {
+        let mut gradient = RadialGradient::new(vec![(NamedColor::White, 0.0).into(), (NamedColor::Black, 1.0).into()]);
+        assert_eq!(Image::from(gradient.clone()).into_css(), "radial-gradient(white 0%, black 100%)");
+
+        gradient.shape = Some(RadialShape::Circle);
+        gradient.size = Some(RadialSize::Radius(Length::Px(50.0)));
+        gradient.position = Some((PositionKeyword::Left, 20).into());
+        assert_eq!(Image::from(gradient).into_css(), "radial-gradient(circle 50px at left 20px, white 0%, black 100%)");
+    }
+
+    #[test]
+    fn conic_gradient() {
+        let mut gradient = ConicGradient::new(vec![(NamedColor::Red, Angle::Turn(0.25)).into(), NamedColor::Blue.into()]);
+        gradient.from = Some(Angle::Deg(45.0));
+        gradient.position = Some(PositionKeyword::Center.into());
+        assert_eq!(Image::from(gradient).into_css(), "conic-gradient(from 45deg at center center, red 0.25turn, blue)");
+    }
+}
@@ -0,0 +1,282 @@
+use crate::css_enum;
+
+use super::{quantities::Length, IntoCss};
+
+css_enum!(MediaType; All | Print | Screen);
+css_enum!(ColorScheme; Light | Dark);
+css_enum!(ReducedMotion; NoPreference "no-preference" | Reduce);
+css_enum!(Hover; None | Hover);
+css_enum!(Pointer; None | Coarse | Fine);
+css_enum!(Orientation; Portrait | Landscape);
+
+/// A single `(name: value)` test of a media query
+#[derive(Clone)]
+pub enum MediaFeature {
+    Width(Length),
+    MinWidth(Length),
+    MaxWidth(Length),
+    Height(Length),
+    MinHeight(Length),
+    MaxHeight(Length),
+    Orientation(Orientation),
+    PrefersColorScheme(ColorScheme),
+    PrefersReducedMotion(ReducedMotion),
+    /// Whether the primary input can hover
+    Hover(Hover),
+    /// Whether any input can hover
+    AnyHover(Hover),
+    /// The accuracy of the primary pointing device
+    Pointer(Pointer),
+    /// The accuracy of the most accurate pointing device
+    AnyPointer(Pointer),
+}
+
+impl IntoCss for MediaFeature {
+    fn into_css(self) -> String {
+        let (name, value) = match self {
+            Self::Width(length) => ("width", length.into_css()),
+            Self::MinWidth(length) => ("min-width", length.into_css()),
+            Self::MaxWidth(length) => ("max-width", length.into_css()),
+            Self::Height(length) => ("height", length.into_css()),
+            Self::MinHeight(length) => ("min-height", length.into_css()),
+            Self::MaxHeight(length) => ("max-height", length.into_css()),
+            Self::Orientation(orientation) => ("orientation", orientation.into_css()),
+            Self::PrefersColorScheme(scheme) => ("prefers-color-scheme", scheme.into_css()),
+            Self::PrefersReducedMotion(motion) => ("prefers-reduced-motion", motion.into_css()),
+            Self::Hover(hover) => ("hover", hover.into_css()),
+            Self::AnyHover(hover) => ("any-hover", hover.into_css()),
+            Self::Pointer(pointer) => ("pointer", pointer.into_css()),
+            Self::AnyPointer(pointer) => ("any-pointer", pointer.into_css()),
+        };
+        format!("({name}: {value})")
+    }
+}
+
+/// Features combined with `and`, `or` and `not`, the condition of media and container queries
+#[derive(Clone)]
+pub enum Condition<F> {
+    Feature(F),
+    And(Vec<Condition<F>>),
+    Or(Vec<Condition<F>>),
+    Not(Box<Condition<F>>),
+}
+
+impl<F> Condition<F> {
+    pub fn and(self, other: impl Into<Condition<F>>) -> Self {
+        let mut conditions = match self {
+            Self::And(conditions) => conditions,
+            x => vec![x],
+        };
+        match other.into() {
+            Self::And(other) => conditions.extend(other),
+            other => conditions.push(other),
+        }
+        Self::And(conditions)
+    }
+
+    pub fn or(self, other: impl Into<Condition<F>>) -> Self {
+        let mut conditions = match self {
+            Self::Or(conditions) => conditions,
+            x => vec![x],
+        };
+        match other.into() {
+            Self::Or(other) => conditions.extend(other),
+            other => conditions.push(other),
+        }
+        Self::Or(conditions)
+    }
+}
+
+impl<F: IntoCss> Condition<F> {
+    /// The condition wrapped in parentheses if it is not a single feature
+    fn in_parens(self) -> String {
+        match self {
+            Self::Feature(feature) => feature.into_css(),
+            x => format!("({})", x.into_css()),
+        }
+    }
+}
+
+/// The features are written in parentheses by their `IntoCss`
+impl<F: IntoCss> IntoCss for Condition<F> {
+    fn into_css(self) -> String {
+        let join = |conditions: Vec<Condition<F>>, separator: &str| {
+            conditions.into_iter().map(Condition::in_parens).collect::<Vec<_>>().join(separator)
+        };
+        match self {
+            Self::Feature(feature) => feature.into_css(),
+            Self::And(conditions) => join(conditions, " and "),
+            Self::Or(conditions) => join(conditions, " or "),
+            Self::Not(condition) => format!("not {}", condition.in_parens()),
+        }
+    }
+}
+
+/// `not condition`
+impl<F> std::ops::Not for Condition<F> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+impl<F> From<F> for Condition<F> {
+    fn from(feature: F) -> Self {
+        Self::Feature(feature)
+    }
+}
+
+/// Media features combined with `and`, `or` and `not`
+pub type MediaCondition = Condition<MediaFeature>;
+
+impl MediaCondition {
+    /// `(min-width: min)`
+    pub fn min_width(min: impl Into<Length>) -> Self {
+        MediaFeature::MinWidth(min.into()).into()
+    }
+
+    /// `(max-width: max)`
+    pub fn max_width(max: impl Into<Length>) -> Self {
+        MediaFeature::MaxWidth(max.into()).into()
+    }
+
+    /// `(min-width: min) and (max-width: max)`
+    pub fn width_between(min: impl Into<Length>, max: impl Into<Length>) -> Self {
+        Self::min_width(min).and(Self::max_width(max))
+    }
+
+    /// `(min-height: min) and (max-height: max)`
+    pub fn height_between(min: impl Into<Length>, max: impl Into<Length>) -> Self {
+        Self::from(MediaFeature::MinHeight(min.into())).and(MediaFeature::MaxHeight(max.into()))
+    }
+}
+
+/// The query of an `@media` rule
+#[derive(Clone)]
+pub enum MediaQuery {
+    /// A media type with an optional condition, e.g. `screen and (min-width: 600px)` or `not print`
+    Typed { not: bool, media_type: MediaType, condition: Option<MediaCondition> },
+    Condition(MediaCondition),
+    /// A comma separated list, matches if one of the queries matches
+    List(Vec<MediaQuery>),
+}
+
+impl MediaQuery {
+    pub fn media_type(media_type: MediaType) -> Self {
+        Self::Typed { not: false, media_type, condition: None }
+    }
+
+    pub fn screen() -> Self {
+        Self::media_type(MediaType::Screen)
+    }
+
+    pub fn print() -> Self {
+        Self::media_type(MediaType::Print)
+    }
+
+    /// Matches every device but the ones of `media_type`, e.g. `not print`
+    pub fn not(media_type: MediaType) -> Self {
+        Self::Typed { not: true, media_type, condition: None }
+    }
+
+    /// Adds a condition that has to match as well, to every query of a list
+    pub fn and(self, condition: impl Into<MediaCondition>) -> Self {
+        let condition = condition.into();
+        match self {
+            Self::Typed { not, media_type, condition: existing } => {
+                let condition = match existing {
+                    Some(existing) => existing.and(condition),
+                    None => condition,
+                };
+                Self::Typed { not, media_type, condition: Some(condition) }
+            },
+            Self::Condition(existing) => Self::Condition(existing.and(condition)),
+            Self::List(queries) => Self::List(queries.into_iter().map(|query| query.and(condition.clone())).collect()),
+        }
+    }
+
+    pub fn or(self, other: impl Into<MediaQuery>) -> Self {
+        let mut queries = match self {
+            Self::List(queries) => queries,
+            x => vec![x],
+        };
+        match other.into() {
+            Self::List(other) => queries.extend(other),
+            other => queries.push(other),
+        }
+        Self::List(queries)
+    }
+}
+
+impl IntoCss for MediaQuery {
+    fn into_css(self) -> String {
+        match self {
+            Self::Typed { not, media_type, condition } => {
+                let not = if not { "not " } else { "" };
+                let condition = match condition {
+                    // `or` can only follow a media type in parentheses
+                    Some(condition @ MediaCondition::Or(_)) => format!(" and {}", condition.in_parens()),
+                    Some(condition) => format!(" and {}", condition.into_css()),
+                    None => String::new(),
+                };
+                format!("{not}{}{condition}", media_type.into_css())
+            },
+            Self::Condition(condition) => condition.into_css(),
+            Self::List(queries) => queries.into_iter().map(MediaQuery::into_css).collect::<Vec<_>>().join(", "),
+        }
+    }
+}
+
+impl From<MediaCondition> for MediaQuery {
+    fn from(condition: MediaCondition) -> Self {
+        Self::Condition(condition)
+    }
+}
+
+impl From<MediaFeature> for MediaQuery {
+    fn from(feature: MediaFeature) -> Self {
+        Self::Condition(feature.into())
+    }
+}
+
+impl From<MediaType> for MediaQuery {
+    fn from(media_type: MediaType) -> Self {
+        Self::media_type(media_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css;
+
+    use super::*;
+
+    #[test]
+    fn conditions() {
+        assert_eq!(MediaCondition::width_between(600, 900).into_css(), "(min-width: 600px) and (max-width: 900px)");
+        let condition = MediaCondition::from(MediaFeature::Hover(Hover::Hover))
+            .or(MediaFeature::Pointer(Pointer::Fine))
+            .and(!MediaCondition::from(MediaFeature::PrefersReducedMotion(ReducedMotion::Reduce)));
+        assert_eq!(condition.into_css(), "((hover: hover) or (pointer: fine)) and (not (prefers-reduced-motion: reduce))");
+        assert_eq!((!MediaCondition::max_width(600)).into_css(), "not (max-width: 600px)");
+    }
+
+    #[test]
+    fn queries() {
+        let dark = MediaQuery::screen().and(MediaFeature::PrefersColorScheme(ColorScheme::Dark));
+        assert_eq!(dark.clone().into_css(), "screen and (prefers-color-scheme: dark)");
+        assert_eq!(MediaQuery::not(MediaType::Print).into_css(), "not print");
+        let either = MediaCondition::max_width(600).or(MediaFeature::Orientation(Orientation::Portrait));
+        assert_eq!(MediaQuery::screen().and(either).into_css(), "screen and ((max-width: 600px) or (orientation: portrait))");
+        let list = dark.or(MediaType::Print).and(MediaCondition::min_width(300));
+        assert_eq!(list.into_css(), "screen and (prefers-color-scheme: dark) and (min-width: 300px), print and (min-width: 300px)");
+    }
+
+    #[test]
+    fn blocks_under_a_query() {
+        let mut card = css!{ width: 1.0; };
+        card.set_media(MediaCondition::min_width(600), css!{ width: 0.5; });
+        assert_eq!(card.to_rules(".card"), [".card {width: 100%;}", "@media (min-width: 600px) {.card {width: 50%;}}"]);
+    }
+}
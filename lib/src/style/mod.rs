@@ -1,12 +1,29 @@
 use yew::{html::IntoPropValue, virtual_dom::AttrValue};
 
-use self::{color::Color, image::Image, border::BorderWidth, border::BorderStyle, quantities::{Length, Duration}};
+use self::{color::{Color, WcagLevel}, image::Image, border::BorderWidth, border::BorderStyle, quantities::{Length, Duration}, variable::Var, pseudo::Pseudo, media::MediaQuery, container::{Container, ContainerName, ContainerType, ContainerQuery}, easing::Easing,
+    animation::{Animation, AnimationDirection, AnimationFillMode, AnimationName, AnimationPlayState, IterationCount}, transition::Transition,
+    font::{Font, FontFamily, FontFeature, FontKerning, FontLanguageOverride, FontOpticalSizing, FontPalette, FontSize, FontSizeAdjust,
+        FontStretch, FontStyle, FontSynthesis, FontSynthesisMode, FontVariant, FontVariantAlternates, FontVariantCaps, FontVariantEastAsian,
+        FontVariantEmoji, FontVariantLigatures, FontVariantNumeric, FontVariantPosition, FontVariation, FontWeight},
+    grid::{Grid, GridArea, GridAutoFlow, GridLine, GridPlacement, GridTemplate, GridTemplateAreas, TrackList, TrackSize}};
 
 pub mod color;
 pub mod border;
 pub mod image;
 pub mod url;
 pub mod quantities;
+pub mod calc;
+pub mod variable;
+pub mod pseudo;
+pub mod sass;
+pub mod selector;
+pub mod media;
+pub mod container;
+pub mod easing;
+pub mod animation;
+pub mod transition;
+pub mod font;
+pub mod grid;
 
 #[derive(Clone)]
 pub struct TODO;
@@ -25,7 +42,12 @@ pub enum CssField<T: IntoCss> {
     None,
     Some(T),
     Initial,
-    Inherit
+    Inherit,
+    Unset,
+    Revert,
+    RevertLayer,
+    /// A `var()` reference, the fallback must have the type of the field
+    Var(Var<T>)
 }
 
 pub trait IntoCss {
@@ -41,6 +63,28 @@ impl<T: IntoCss> IntoCss for Option<T> {
     }
 }
 
+/// Values separated by commas, e.g. `fade, spin`
+#[derive(Clone)]
+pub struct CommaList<T>(pub Vec<T>);
+
+impl<T: IntoCss> IntoCss for CommaList<T> {
+    fn into_css(self) -> String {
+        self.0.into_iter().map(IntoCss::into_css).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl<T> From<T> for CommaList<T> {
+    fn from(value: T) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl<T> From<Vec<T>> for CommaList<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
 impl<A: IntoCss, B: IntoCss, C: IntoCss> IntoCss for (A, B, C) {
     fn into_css(self) -> String {
         let (a, b, c) = self;
@@ -76,7 +120,11 @@ impl<T: IntoCss> IntoCss for CssField<T> {
             Self::None => "".to_string(),
             Self::Inherit => "inherit".to_string(),
             Self::Initial => "initial".to_string(),
-            Self::Some(x) => x.into_css()
+            Self::Unset => "unset".to_string(),
+            Self::Revert => "revert".to_string(),
+            Self::RevertLayer => "revert-layer".to_string(),
+            Self::Some(x) => x.into_css(),
+            Self::Var(x) => x.into_css()
         }
     }
 }
@@ -84,7 +132,7 @@ impl<T: IntoCss> IntoCss for CssField<T> {
 #[macro_export]
 macro_rules! css_enum {
     ($name:ident; $($variant:tt $($display_name:literal)?)|*) => {
-        #[derive(Clone, Copy)]
+        #[derive(Clone, Copy, Debug)]
         pub enum $name {
             $($variant),*
         }
@@ -116,6 +164,13 @@ impl IntoCss for () {
     }
 }
 
+/// A raw css value, used for custom properties which have no type
+impl IntoCss for String {
+    fn into_css(self) -> String {
+        self
+    }
+}
+
 impl<T: IntoCss> From<Option<T>> for CssField<T> {
     fn from(option: Option<T>) -> Self {
         match option {
@@ -125,33 +180,51 @@ impl<T: IntoCss> From<Option<T>> for CssField<T> {
     }
 }
 
-impl<A: IntoCss> From<(A,)> for CssField<A> {
-    fn from((a,): (A,)) -> Self {
-        CssField::Some(a)
+impl<A: IntoCss> From<(CssField<A>,)> for CssField<A> {
+    fn from((a,): (CssField<A>,)) -> Self {
+        a
+    }
+}
+
+impl<A: IntoCss, B: IntoCss, C: IntoCss> From<(CssField<A>,)> for CssField<(CssField<A>, CssField<B>, CssField<C>)> {
+    fn from((a,): (CssField<A>,)) -> Self {
+        CssField::Some((a, CssField::None, CssField::None))
+    }
+}
+
+impl<A: IntoCss, B: IntoCss, C: IntoCss> From<(CssField<A>, CssField<B>)> for CssField<(CssField<A>, CssField<B>, CssField<C>)> {
+    fn from((a, b): (CssField<A>, CssField<B>)) -> Self {
+        CssField::Some((a, b, CssField::None))
+    }
+}
+
+impl<A: IntoCss, B: IntoCss, C: IntoCss> From<(CssField<A>, CssField<B>, CssField<C>)> for CssField<(CssField<A>, CssField<B>, CssField<C>)> {
+    fn from(abc: (CssField<A>, CssField<B>, CssField<C>)) -> Self {
+        CssField::Some(abc)
     }
 }
 
-impl<A: IntoCss, B: IntoCss, C: IntoCss> From<(A, B)> for CssField<(CssField<A>, CssField<B>, CssField<C>)> {
-    fn from((a, b): (A, B)) -> Self {
-        CssField::Some((CssField::Some(a), CssField::Some(b), CssField::None))
+impl<A: IntoCss, B: IntoCss, C: IntoCss, D: IntoCss> From<(CssField<A>,)> for CssField<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> {
+    fn from((a,): (CssField<A>,)) -> Self {
+        CssField::Some((a, CssField::None, CssField::None, CssField::None))
     }
 }
 
-impl<A: IntoCss, B: IntoCss, C: IntoCss> From<(A, B, C)> for CssField<(CssField<A>, CssField<B>, CssField<C>)> {
-    fn from((a, b, c): (A, B, C)) -> Self {
-        CssField::Some((CssField::Some(a), CssField::Some(b), CssField::Some(c)))
+impl<A: IntoCss, B: IntoCss, C: IntoCss, D: IntoCss> From<(CssField<A>, CssField<B>)> for CssField<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> {
+    fn from((a, b): (CssField<A>, CssField<B>)) -> Self {
+        CssField::Some((a, b, CssField::None, CssField::None))
     }
 }
 
-impl<A: IntoCss, B: IntoCss, C: IntoCss, D: IntoCss> From<(A, B, C)> for CssField<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> {
-    fn from((a, b, c): (A, B, C)) -> Self {
-        CssField::Some((CssField::Some(a), CssField::Some(b), CssField::Some(c), CssField::None))
+impl<A: IntoCss, B: IntoCss, C: IntoCss, D: IntoCss> From<(CssField<A>, CssField<B>, CssField<C>)> for CssField<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> {
+    fn from((a, b, c): (CssField<A>, CssField<B>, CssField<C>)) -> Self {
+        CssField::Some((a, b, c, CssField::None))
     }
 }
 
-impl<A: IntoCss, B: IntoCss, C: IntoCss, D: IntoCss> From<(A,)> for CssField<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> {
-    fn from((a,): (A,)) -> Self {
-        CssField::Some((CssField::Some(a.into()), CssField::None, CssField::None, CssField::None))
+impl<A: IntoCss, B: IntoCss, C: IntoCss, D: IntoCss> From<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> for CssField<(CssField<A>, CssField<B>, CssField<C>, CssField<D>)> {
+    fn from(abcd: (CssField<A>, CssField<B>, CssField<C>, CssField<D>)) -> Self {
+        CssField::Some(abcd)
     }
 }
 
@@ -193,6 +266,14 @@ macro_rules! implCSSBlock {
     ($($display:literal = $field_name:ident: $($type_name:ty),+)*) => {
         #[derive(Default, Clone)]
         pub struct CssBlock {
+            /// Custom properties (`--name: value`) declared in this block
+            pub variables: Vec<(String, String)>,
+            /// Blocks for pseudo-classes and pseudo-elements, only used when the block is rendered as rules
+            pub states: Vec<(Pseudo, CssBlock)>,
+            /// Blocks that only apply under a media query, only used when the block is rendered as rules
+            pub media: Vec<(MediaQuery, CssBlock)>,
+            /// Blocks that only apply under a container query, only used when the block is rendered as rules
+            pub container_queries: Vec<(ContainerQuery, CssBlock)>,
             $(
                 pub $field_name: implCSSBlockField!($($type_name),+),
             )*
@@ -201,6 +282,18 @@ macro_rules! implCSSBlock {
         impl CssBlock {
 
             pub fn update(&mut self, other: &CssBlock) {
+                for (name, value) in &other.variables {
+                    self.set_var(name, value.clone());
+                }
+                for (pseudo, block) in &other.states {
+                    self.set_state(*pseudo, block.clone());
+                }
+                for (query, block) in &other.media {
+                    self.set_media(query.clone(), block.clone());
+                }
+                for (query, block) in &other.container_queries {
+                    self.set_container_query(query.clone(), block.clone());
+                }
                 $(
                     self.$field_name = other.$field_name.clone().or(self.$field_name.clone());
                 )*
@@ -213,8 +306,19 @@ macro_rules! implCSSBlock {
             }
         }
 
+        /// The properties of `CssBlock`, named like its fields, e.g. `Property::background_color`
+        #[allow(non_upper_case_globals)]
+        impl Property {
+            $(
+                pub const $field_name: Property = Property($display);
+            )*
+        }
+
         impl std::fmt::Display for CssBlock {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+                for (name, value) in &self.variables {
+                    write!(f, "{}: {};", name, value)?;
+                }
                 $(match self.$field_name.clone() {
                     CssField::None => Ok(()),
                     x => write!(f, "{}: {};", $display, x.into_css()),
@@ -246,40 +350,447 @@ css_enum!(AlignmentBaseline;
 );
 css_enum!(Float; Left | Right);
 
-#[macro_export(local_inner_macros)]
+/// Processes a single line of the `css!` macro.
+/// 
+/// The values of a line are split into slots, one for every part of a shorthand property.
+/// A slot is either a rust expression, a `var(--name, fallback)` reference or one of the css-wide keywords
+/// `initial`, `inherit`, `unset`, `revert` and `revert-layer`.
+#[macro_export]
 macro_rules! css_line {
-    // ($target:ident; border: $width:tt;) => {
-    //     $target.border(Some($width.into()), None, None);
-    // };
-    // ($target:ident; border: $width:tt $style:tt;) => {
-    //     $target.border(Some($width.into()), Some($style.into()), None);
-    // };
-    ($target:ident; $name:ident: $a:tt;) => {
-        $target.$name = ($a.into(),).into();
+    // custom property `--name: value;`
+    ($target:ident; - - $($name:tt)-+ : $($value:tt)+) => {
+        $crate::css_line!(@slots $target; [- - $($name)-+]; []; []; $($value)+);
+    };
+    ($target:ident; $name:ident : $($value:tt)+) => {
+        $crate::css_line!(@slots $target; [$name]; []; []; $($value)+);
+    };
+
+    // all tokens are sorted into slots
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*];) => {
+        $crate::css_line!(@flush $target; $field; [$($slots)*]; [$($tokens)*]);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; var ($($var:tt)*) $($rest:tt)*) => {
+        $crate::css_line!(@push $target; $field; [$($slots)*]; [$($tokens)*]; (@var $($var)*); $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; revert - layer $($rest:tt)*) => {
+        $crate::css_line!(@push $target; $field; [$($slots)*]; [$($tokens)*]; (@keyword RevertLayer); $($rest)*);
+    };
+    // a name next to an operator, call or index is part of an expression, e.g. a local named `inherit` in `inherit + 5`
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; + $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* + $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; - $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* - $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; * $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* * $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; / $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* / $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; % $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* % $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; == $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* == $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; != $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* != $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; < $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* < $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; > $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* > $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; <= $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* <= $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; >= $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* >= $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; && $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* && $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; || $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* || $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; & $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* & $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; | $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* | $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; ^ $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* ^ $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; << $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* << $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; >> $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* >> $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; ! $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* ! $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; . $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* . $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; :: $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* :: $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; as $next:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* as $next]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident + $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name +]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident - $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name -]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident * $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name *]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident / $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name /]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident % $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name %]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident == $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name ==]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident != $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name !=]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident < $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name <]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident > $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name >]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident <= $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name <=]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident >= $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name >=]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident && $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name &&]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident || $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name ||]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident & $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name &]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident | $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name |]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident ^ $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name ^]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident << $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name <<]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident >> $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name >>]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident ! $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name !]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident . $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name .]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident :: $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name ::]; $($rest)*);
     };
-    ($target:ident; $name:ident: $a:tt $b:tt ;) => {
-        $target.$name = ($a.into(), $b.into()).into();
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident as $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name as]; $($rest)*);
     };
-    ($target:ident; $name:ident: $a:tt $b:tt $c:tt ;) => {
-        $target.$name = ($a.into(), $b.into(), $c.into()).into();
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident ? $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name ?]; $($rest)*);
     };
-    ($target:ident; $name:ident: $a:tt $b:tt $c:tt $d:tt ;) => {
-        $target.$name = ($a.into(), $b.into(), $c.into(), $d.into()).into();
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident ($($args:tt)*) $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name ($($args)*)]; $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $name:ident [$($index:tt)*] $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $name [$($index)*]]; $($rest)*);
+    };
+    // otherwise `initial`, `inherit`, `unset` and `revert` make up a whole slot and are keywords
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; initial $($rest:tt)*) => {
+        $crate::css_line!(@push $target; $field; [$($slots)*]; [$($tokens)*]; (@keyword Initial); $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; inherit $($rest:tt)*) => {
+        $crate::css_line!(@push $target; $field; [$($slots)*]; [$($tokens)*]; (@keyword Inherit); $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; unset $($rest:tt)*) => {
+        $crate::css_line!(@push $target; $field; [$($slots)*]; [$($tokens)*]; (@keyword Unset); $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; revert $($rest:tt)*) => {
+        $crate::css_line!(@push $target; $field; [$($slots)*]; [$($tokens)*]; (@keyword Revert); $($rest)*);
+    };
+    (@slots $target:ident; $field:tt; [$($slots:tt)*]; [$($tokens:tt)*]; $token:tt $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)*]; [$($tokens)* $token]; $($rest)*);
+    };
+    // the collected tokens form complete expressions, turn them into slots before adding a special slot
+    (@push $target:ident; $field:tt; [$($slots:tt)*]; [$($value:expr)*]; $slot:tt; $($rest:tt)*) => {
+        $crate::css_line!(@slots $target; $field; [$($slots)* $(($value))* $slot]; []; $($rest)*);
+    };
+    (@flush $target:ident; $field:tt; [$($slots:tt)*]; [$($value:expr)*]) => {
+        $crate::css_line!(@assign $target; $field; $($slots)* $(($value))*);
+    };
+
+    (@assign $target:ident; [- - $($name:tt)-+]; $($slot:tt)+) => {
+        $target.set_var(
+            concat!("-", $("-", stringify!($name)),+),
+            [$($crate::css_line!(@custom $slot)),+].join(" ")
+        );
+    };
+    // a single keyword or `var()` replaces the whole value, even for shorthand properties
+    (@assign $target:ident; [$name:ident]; (@keyword $keyword:ident)) => {
+        $target.$name = $crate::style::CssField::$keyword;
+    };
+    (@assign $target:ident; [$name:ident]; (@var $($var:tt)*)) => {
+        $target.$name = $crate::style::CssField::Var($crate::css_line!(@var $($var)*));
+    };
+    (@assign $target:ident; [$name:ident]; $($slot:tt)+) => {
+        $target.$name = ($($crate::css_line!(@slot $slot),)+).into();
+    };
+
+    (@slot (@keyword $keyword:ident)) => {
+        compile_error!("css-wide keywords have to be the whole value, they cannot be mixed with other values")
+    };
+    (@slot (@var $($var:tt)*)) => {
+        $crate::style::CssField::Var($crate::css_line!(@var $($var)*))
+    };
+    (@slot ($value:expr)) => {
+        $crate::style::CssField::Some($value.into())
+    };
+
+    (@custom (@keyword $keyword:ident)) => {
+        $crate::style::IntoCss::into_css($crate::style::CssField::<()>::$keyword)
+    };
+    // custom properties are untyped, so the fallback can be any value
+    (@custom (@var - - $($name:tt)-+)) => {
+        $crate::style::IntoCss::into_css($crate::style::variable::Var::<String>::new(
+            concat!("-", $("-", stringify!($name)),+)
+        ))
+    };
+    (@custom (@var - - $($name:tt)-+ , $fallback:expr)) => {
+        $crate::style::IntoCss::into_css($crate::style::variable::Var::<String>::with_fallback(
+            concat!("-", $("-", stringify!($name)),+),
+            $crate::style::IntoCss::into_css($fallback)
+        ))
+    };
+    (@custom ($value:expr)) => {
+        $crate::style::IntoCss::into_css($value)
+    };
+
+    (@var - - $($name:tt)-+) => {
+        $crate::style::variable::Var::new(concat!("-", $("-", stringify!($name)),+))
+    };
+    (@var - - $($name:tt)-+ , $fallback:expr) => {
+        $crate::style::variable::Var::with_fallback(concat!("-", $("-", stringify!($name)),+), $fallback)
+    };
+}
+
+/// Splits the body of the `css!` macro into lines.
+/// 
+/// A line of up to eight tokens is split off in one step and longer lines take one more step per eight tokens,
+/// so a block needs about one level of recursion per declaration.
+#[macro_export]
+macro_rules! css_lines {
+    ($target:ident; []) => {};
+    // `&:hover { ... }` and `&::before { ... }`, unknown names fail to compile
+    ($target:ident; [] & : $first:ident $(- $name:ident)* { $($block:tt)* } $($rest:tt)*) => {
+        $target.set_state({
+            const CLASS: $crate::style::pseudo::PseudoClass = match $crate::style::pseudo::PseudoClass::from_name(
+                concat!(stringify!($first) $(, "-", stringify!($name))*)
+            ) {
+                Some(class) => class,
+                None => panic!(concat!("unknown pseudo-class :", stringify!($first) $(, "-", stringify!($name))*)),
+            };
+            CLASS
+        }, $crate::css!($($block)*));
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [] & :: $first:ident $(- $name:ident)* { $($block:tt)* } $($rest:tt)*) => {
+        $target.set_state({
+            const ELEMENT: $crate::style::pseudo::PseudoElement = match $crate::style::pseudo::PseudoElement::from_name(
+                concat!(stringify!($first) $(, "-", stringify!($name))*)
+            ) {
+                Some(element) => element,
+                None => panic!(concat!("unknown pseudo-element ::", stringify!($first) $(, "-", stringify!($name))*)),
+            };
+            ELEMENT
+        }, $crate::css!($($block)*));
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)*);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b $c);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt $d:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b $c $d);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b $c $d $e);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b $c $d $e $f);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b $c $d $e $f $g);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt $h:tt ; $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $a $b $c $d $e $f $g $h);
+        $crate::css_lines!($target; [] $($rest)*);
+    };
+    ($target:ident; [$($line:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt $h:tt $($rest:tt)*) => {
+        $crate::css_lines!($target; [$($line)* $a $b $c $d $e $f $g $h] $($rest)*);
+    };
+    // last line without a trailing `;`
+    ($target:ident; [$($line:tt)*] $($rest:tt)*) => {
+        $crate::css_line!($target; $($line)* $($rest)*);
     };
 }
 
+/// Builds a `CssBlock`, see the README for the syntax.
+/// 
+/// A css-wide keyword replaces the whole value and cannot be mixed with other values:
+/// ```
+/// # use yewcss::{css, style::border::BorderStyle};
+/// css!{ border: inherit; };
+/// css!{ border: 1 BorderStyle::Solid; };
+/// ```
+/// ```compile_fail
+/// # use yewcss::{css, style::border::BorderStyle};
+/// css!{ border: 1 BorderStyle::Solid inherit; };
+/// ```
 #[macro_export]
 macro_rules! css {
-    ($($name:ident: $($value:expr) +;)*) => ({
+    ($($tokens:tt)*) => ({
         let mut x = $crate::style::CssBlock::default();
-        $(
-            $crate::css_line!(x; $name: $($value)+ ;);
-        )*
+        $crate::css_lines!(x; [] $($tokens)*);
         x
     });
 }
 
 
+impl CssBlock {
+    /// Declares the custom property `name`, replacing an earlier declaration of the same name.
+    /// 
+    /// The leading `--` of the name is optional.
+    pub fn set_var(&mut self, name: &str, value: impl IntoCss) {
+        let name = variable::property_name(name);
+        let value = value.into_css();
+        match self.variables.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value,
+            None => self.variables.push((name, value))
+        }
+    }
+
+    /// Adds the block for a pseudo-class or pseudo-element, merging it into an existing block for the same one
+    pub fn set_state(&mut self, pseudo: impl Into<Pseudo>, block: CssBlock) {
+        let pseudo = pseudo.into();
+        match self.states.iter_mut().find(|(p, _)| *p == pseudo) {
+            Some((_, state)) => state.update(&block),
+            None => self.states.push((pseudo, block))
+        }
+    }
+
+    /// Adds a block that only applies under `query`, merging it into an existing block for the same query
+    pub fn set_media(&mut self, query: impl Into<MediaQuery>, block: CssBlock) {
+        let query = query.into();
+        let css = query.clone().into_css();
+        match self.media.iter_mut().find(|(q, _)| q.clone().into_css() == css) {
+            Some((_, media)) => media.update(&block),
+            None => self.media.push((query, block))
+        }
+    }
+
+    /// Adds a block that only applies under `query`, merging it into an existing block for the same query
+    pub fn set_container_query(&mut self, query: impl Into<ContainerQuery>, block: CssBlock) {
+        let query = query.into();
+        let css = query.clone().into_css();
+        match self.container_queries.iter_mut().find(|(q, _)| q.clone().into_css() == css) {
+            Some((_, container)) => container.update(&block),
+            None => self.container_queries.push((query, block))
+        }
+    }
+
+    /// The style rules of the block for `selector`, e.g. `.my-class {width: 20px;}`,
+    /// followed by the rules of the pseudo-classes and pseudo-elements, e.g. `.my-class:hover {width: 30px;}`,
+    /// and the `@media` and `@container` rules, e.g. `@media (min-width: 600px) {.my-class {width: 40px;}}`
+    pub fn to_rules(&self, selector: &str) -> Vec<String> {
+        let declarations = self.to_string();
+        let mut rules = Vec::new();
+        if !declarations.is_empty() || (self.states.is_empty() && self.media.is_empty() && self.container_queries.is_empty()) {
+            rules.push(format!("{selector} {{{declarations}}}"));
+        }
+        for (pseudo, block) in &self.states {
+            let pseudo = pseudo.into_css();
+            let selector: Vec<String> = sass::split_selector_list(selector).iter().map(|s| format!("{s}{pseudo}")).collect();
+            rules.extend(block.to_rules(&selector.join(", ")));
+        }
+        for (query, block) in &self.media {
+            rules.push(format!("@media {} {{{}}}", query.clone().into_css(), block.to_rules(selector).concat()));
+        }
+        for (query, block) in &self.container_queries {
+            rules.push(format!("@container {} {{{}}}", query.clone().into_css(), block.to_rules(selector).concat()));
+        }
+        rules
+    }
+
+    /// The contrast ratio of `color` on `background_color`.
+    /// 
+    /// `None` if one of them is not set or can only be resolved by the browser.
+    pub fn contrast_ratio(&self) -> Option<f64> {
+        match (&self.color, &self.background_color) {
+            (CssField::Some(color), CssField::Some(background)) => color.contrast_ratio(background),
+            _ => None
+        }
+    }
+
+    /// Whether `color` on `background_color` meets `level`, see `contrast_ratio`
+    pub fn meets_contrast(&self, level: WcagLevel) -> Option<bool> {
+        self.contrast_ratio().map(|ratio| ratio >= level.min_ratio())
+    }
+}
+
+/// The name of a css property, the constants for the fields of `CssBlock` make sure it exists
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Property(&'static str);
+
+impl Property {
+    pub fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+impl IntoCss for Property {
+    fn into_css(self) -> String {
+        self.0.to_string()
+    }
+}
+
 impl IntoPropValue<Option<AttrValue>> for CssBlock {
     fn into_prop_value(self) -> Option<AttrValue> {
         Some(self.to_string().into())
@@ -290,12 +801,6 @@ pub trait IntoStyle {
     fn into_style(self) -> CssBlock;
 }
 
-// struct SassItem {
-//     query: String,
-//     css: CssBlock,
-//     children: Vec<Box<SassItem>>,
-// }
-
 // "position" = position: Position [Static | Relative | Absolute | Fixed | Inherit]
 implCSSBlock! {
 
@@ -304,16 +809,16 @@ implCSSBlock! {
     "align-items" = align_items: AlignItems
     "align-self" = align_self: AlignSelf
     "alignment-baseline" = alignment_baseline: AlignmentBaseline
-    "all" = all: () // all only allows initial, inherit or unset so it has no inner type
-    "animation" = animation: TODO //TODO: Type
-    "animation-delay" = animation_delay: Duration
-    "animation-direction" = animation_direction: TODO //TODO: Type
-    "animation-duration" = animation_duration: Duration //TODO: Type
-    "animation-fill-mode" = animation_fill_mode: TODO //TODO: Type
-    "animation-iteration-count" = animation_iteration_count: TODO //TODO: Type
-    "animation-name" = animation_name: TODO //TODO: Type
-    "animation-play-state" = animation_play_state: TODO //TODO: Type
-    "animation-timing-function" = animation_timing_function: TODO //TODO: Type
+    "all" = all: () // all only allows the css-wide keywords so it has no inner type
+    "animation" = animation: CommaList<Animation>
+    "animation-delay" = animation_delay: CommaList<Duration>
+    "animation-direction" = animation_direction: CommaList<AnimationDirection>
+    "animation-duration" = animation_duration: CommaList<Duration>
+    "animation-fill-mode" = animation_fill_mode: CommaList<AnimationFillMode>
+    "animation-iteration-count" = animation_iteration_count: CommaList<IterationCount>
+    "animation-name" = animation_name: CommaList<AnimationName>
+    "animation-play-state" = animation_play_state: CommaList<AnimationPlayState>
+    "animation-timing-function" = animation_timing_function: CommaList<Easing>
     "appearance" = appearance: TODO //TODO: Type
     "aspect-ratio" = aspect_ratio: TODO //TODO: Type
     "azimuth" = azimuth: TODO //TODO: Type
@@ -439,9 +944,9 @@ implCSSBlock! {
     "contain-intrinsic-inline-size" = contain_intrinsic_inline_size: TODO //TODO: Type
     "contain-intrinsic-size" = contain_intrinsic_size: TODO //TODO: Type
     "contain-intrinsic-width" = contain_intrinsic_width: Length
-    "container" = container: TODO //TODO: Type
-    "container-name" = container_name: TODO //TODO: Type
-    "container-type" = container_type: TODO //TODO: Type
+    "container" = container: Container
+    "container-name" = container_name: ContainerName
+    "container-type" = container_type: ContainerType
     "content" = content: TODO //TODO: Type
     "content-visibility" = content_visibility: TODO //TODO: Type
     "continue" = _continue: TODO //TODO: Type
@@ -484,51 +989,51 @@ implCSSBlock! {
     "flow" = flow: TODO //TODO: Type
     "flow-from" = flow_from: TODO //TODO: Type
     "flow-into" = flow_into: TODO //TODO: Type
-    "font" = font: TODO //TODO: Type
-    "font-family" = font_family: TODO //TODO: Type
-    "font-feature-settings" = font_feature_settings: TODO //TODO: Type
-    "font-kerning" = font_kerning: TODO //TODO: Type
-    "font-language-override" = font_language_override: TODO //TODO: Type
-    "font-optical-sizing" = font_optical_sizing: TODO //TODO: Type
-    "font-palette" = font_palette: TODO //TODO: Type
-    "font-size" = font_size: TODO //TODO: Type
-    "font-size-adjust" = font_size_adjust: TODO //TODO: Type
-    "font-stretch" = font_stretch: TODO //TODO: Type
-    "font-style" = font_style: TODO //TODO: Type
-    "font-synthesis" = font_synthesis: TODO //TODO: Type
-    "font-synthesis-small-caps" = font_synthesis_small_caps: TODO //TODO: Type
-    "font-synthesis-style" = font_synthesis_style: TODO //TODO: Type
-    "font-synthesis-weight" = font_synthesis_weight: TODO //TODO: Type
-    "font-variant" = font_variant: TODO //TODO: Type
-    "font-variant-alternates" = font_variant_alternates: TODO //TODO: Type
-    "font-variant-caps" = font_variant_caps: TODO //TODO: Type
-    "font-variant-east-asian" = font_variant_east_asian: TODO //TODO: Type
-    "font-variant-emoji" = font_variant_emoji: TODO //TODO: Type
-    "font-variant-ligatures" = font_variant_ligatures: TODO //TODO: Type
-    "font-variant-numeric" = font_variant_numeric: TODO //TODO: Type
-    "font-variant-position" = font_variant_position: TODO //TODO: Type
-    "font-variation-settings" = font_variation_settings: TODO //TODO: Type
-    "font-weight" = font_weight: TODO //TODO: Type
+    "font" = font: Font
+    "font-family" = font_family: CommaList<FontFamily>
+    "font-feature-settings" = font_feature_settings: CommaList<FontFeature>
+    "font-kerning" = font_kerning: FontKerning
+    "font-language-override" = font_language_override: FontLanguageOverride
+    "font-optical-sizing" = font_optical_sizing: FontOpticalSizing
+    "font-palette" = font_palette: FontPalette
+    "font-size" = font_size: FontSize
+    "font-size-adjust" = font_size_adjust: FontSizeAdjust
+    "font-stretch" = font_stretch: FontStretch
+    "font-style" = font_style: FontStyle
+    "font-synthesis" = font_synthesis: FontSynthesis
+    "font-synthesis-small-caps" = font_synthesis_small_caps: FontSynthesisMode
+    "font-synthesis-style" = font_synthesis_style: FontSynthesisMode
+    "font-synthesis-weight" = font_synthesis_weight: FontSynthesisMode
+    "font-variant" = font_variant: FontVariant
+    "font-variant-alternates" = font_variant_alternates: FontVariantAlternates
+    "font-variant-caps" = font_variant_caps: FontVariantCaps
+    "font-variant-east-asian" = font_variant_east_asian: FontVariantEastAsian
+    "font-variant-emoji" = font_variant_emoji: FontVariantEmoji
+    "font-variant-ligatures" = font_variant_ligatures: FontVariantLigatures
+    "font-variant-numeric" = font_variant_numeric: FontVariantNumeric
+    "font-variant-position" = font_variant_position: FontVariantPosition
+    "font-variation-settings" = font_variation_settings: CommaList<FontVariation>
+    "font-weight" = font_weight: FontWeight
     "footnote-display" = footnote_display: TODO //TODO: Type
     "footnote-policy" = footnote_policy: TODO //TODO: Type
     "forced-color-adjust" = forced_color_adjust: TODO //TODO: Type
     "gap" = gap: TODO //TODO: Type
     "glyph-orientation-vertical" = glyph_orientation_vertical: TODO //TODO: Type
-    "grid" = grid: TODO //TODO: Type
-    "grid-area" = grid_area: TODO //TODO: Type
-    "grid-auto-columns" = grid_auto_columns: TODO //TODO: Type
-    "grid-auto-flow" = grid_auto_flow: TODO //TODO: Type
-    "grid-auto-rows" = grid_auto_rows: TODO //TODO: Type
-    "grid-column" = grid_column: TODO //TODO: Type
-    "grid-column-end" = grid_column_end: TODO //TODO: Type
-    "grid-column-start" = grid_column_start: TODO //TODO: Type
-    "grid-row" = grid_row: TODO //TODO: Type
-    "grid-row-end" = grid_row_end: TODO //TODO: Type
-    "grid-row-start" = grid_row_start: TODO //TODO: Type
-    "grid-template" = grid_template: TODO //TODO: Type
-    "grid-template-areas" = grid_template_areas: TODO //TODO: Type
-    "grid-template-columns" = grid_template_columns: TODO //TODO: Type
-    "grid-template-rows" = grid_template_rows: TODO //TODO: Type
+    "grid" = grid: Grid
+    "grid-area" = grid_area: GridArea
+    "grid-auto-columns" = grid_auto_columns: TrackSize
+    "grid-auto-flow" = grid_auto_flow: GridAutoFlow
+    "grid-auto-rows" = grid_auto_rows: TrackSize
+    "grid-column" = grid_column: GridPlacement
+    "grid-column-end" = grid_column_end: GridLine
+    "grid-column-start" = grid_column_start: GridLine
+    "grid-row" = grid_row: GridPlacement
+    "grid-row-end" = grid_row_end: GridLine
+    "grid-row-start" = grid_row_start: GridLine
+    "grid-template" = grid_template: GridTemplate
+    "grid-template-areas" = grid_template_areas: GridTemplateAreas
+    "grid-template-columns" = grid_template_columns: TrackList
+    "grid-template-rows" = grid_template_rows: TrackList
     "hanging-punctuation" = hanging_punctuation: TODO //TODO: Type
     "height" = height: Length
     "hyphenate-character" = hyphenate_character: TODO //TODO: Type
@@ -810,11 +1315,11 @@ implCSSBlock! {
     "transform-box" = transform_box: TODO //TODO: Type
     "transform-origin" = transform_origin: TODO //TODO: Type
     "transform-style" = transform_style: TODO //TODO: Type
-    "transition" = transition: TODO //TODO: Type
-    "transition-delay" = transition_delay: TODO //TODO: Type
-    "transition-duration" = transition_duration: TODO //TODO: Type
-    "transition-property" = transition_property: TODO //TODO: Type
-    "transition-timing-function" = transition_timing_function: TODO //TODO: Type
+    "transition" = transition: CommaList<Transition>
+    "transition-delay" = transition_delay: CommaList<Duration>
+    "transition-duration" = transition_duration: CommaList<Duration>
+    "transition-property" = transition_property: CommaList<Property>
+    "transition-timing-function" = transition_timing_function: CommaList<Easing>
     "translate" = translate: TODO //TODO: Type
     "unicode-bidi" = unicode_bidi: TODO //TODO: Type
     "user-select" = user_select: TODO //TODO: Type
@@ -846,3 +1351,43 @@ implCSSBlock! {
     "writing-mode" = writing_mode: TODO //TODO: Type
     "z-index" = z_index: TODO //TODO: Type
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{border::BorderStyle, color::NamedColor, IntoCss};
+
+    #[test]
+    fn large_blocks_compile() {
+        // every declaration costs one level of macro recursion
+        let block = css!{
+            width: 1; width: 2; width: 3; width: 4; width: 5; width: 6; width: 7; width: 8; width: 9; width: 10;
+            width: 11; width: 12; width: 13; width: 14; width: 15; width: 16; width: 17; width: 18; width: 19; width: 20;
+            width: 21; width: 22; width: 23; width: 24; width: 25; width: 26; width: 27; width: 28; width: 29; width: 30;
+            width: 31; width: 32; width: 33; width: 34; width: 35; width: 36; width: 37; width: 38; width: 39; width: 40;
+            width: 41; width: 42; width: 43; width: 44; width: 45; width: 46; width: 47; width: 48; width: 49; width: 50;
+            width: 51; width: 52; width: 53; width: 54; width: 55; width: 56; width: 57; width: 58; width: 59; width: 60;
+            width: 61; width: 62; width: 63; width: 64; width: 65; width: 66; width: 67; width: 68; width: 69; width: 70;
+            width: 71; width: 72; width: 73; width: 74; width: 75; width: 76; width: 77; width: 78; width: 79; width: 80;
+            width: 81; width: 82; width: 83; width: 84; width: 85; width: 86; width: 87; width: 88; width: 89; width: 90;
+            width: 91; width: 92; width: 93; width: 94; width: 95; width: 96; width: 97; width: 98; width: 99; width: 100;
+        };
+        assert_eq!(block.width.into_css(), "100px");
+    }
+
+    #[test]
+    fn keywords_replace_the_value() {
+        assert_eq!(css!{ width: inherit; }.width.into_css(), "inherit");
+        assert_eq!(css!{ width: revert-layer; }.width.into_css(), "revert-layer");
+        assert_eq!(css!{ border: initial; }.border.into_css(), "initial");
+    }
+
+    #[test]
+    fn keyword_names_in_expressions_are_rust_values() {
+        let inherit = 10;
+        let unset = NamedColor::Red;
+        assert_eq!(css!{ width: inherit + 5; }.width.into_css(), "15px");
+        assert_eq!(css!{ width: 5 + inherit; }.width.into_css(), "15px");
+        assert_eq!(css!{ width: inherit.max(20); }.width.into_css(), "20px");
+        assert_eq!(css!{ border: (inherit) BorderStyle::Solid unset.clone(); }.border.into_css(), "10px solid red");
+    }
+}
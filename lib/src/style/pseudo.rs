@@ -0,0 +1,126 @@
+use super::IntoCss;
+
+/// Like `css_enum!`, with the css names as literals so they can be looked up at compile time by the `css!` macro
+macro_rules! pseudo_enum {
+    ($name:ident; $($variant:ident $display_name:literal)|*) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            /// The css name without the leading colons
+            pub fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => $display_name),*
+                }
+            }
+
+            /// Looks up the css name, e.g. `focus-visible`
+            pub const fn from_name(name: &str) -> Option<Self> {
+                $(
+                    if str_eq(name, $display_name) {
+                        return Some($name::$variant);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+pseudo_enum!(PseudoClass;
+    Active "active" | AnyLink "any-link" | Autofill "autofill" | Checked "checked" | Default "default" |
+    Defined "defined" | Disabled "disabled" | Empty "empty" | Enabled "enabled" | FirstChild "first-child" |
+    FirstOfType "first-of-type" | Focus "focus" | FocusVisible "focus-visible" | FocusWithin "focus-within" |
+    Fullscreen "fullscreen" | Hover "hover" | InRange "in-range" | Indeterminate "indeterminate" |
+    Invalid "invalid" | LastChild "last-child" | LastOfType "last-of-type" | Link "link" | Modal "modal" |
+    OnlyChild "only-child" | OnlyOfType "only-of-type" | Optional "optional" | OutOfRange "out-of-range" |
+    PlaceholderShown "placeholder-shown" | ReadOnly "read-only" | ReadWrite "read-write" | Required "required" |
+    Root "root" | Target "target" | UserInvalid "user-invalid" | UserValid "user-valid" | Valid "valid" | Visited "visited"
+);
+
+pseudo_enum!(PseudoElement;
+    After "after" | Backdrop "backdrop" | Before "before" | Cue "cue" | FileSelectorButton "file-selector-button" |
+    FirstLetter "first-letter" | FirstLine "first-line" | GrammarError "grammar-error" | Marker "marker" |
+    Placeholder "placeholder" | Selection "selection" | SpellingError "spelling-error"
+);
+
+/// A pseudo-class or pseudo-element that selects a state or part of an element
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pseudo {
+    Class(PseudoClass),
+    Element(PseudoElement),
+}
+
+impl IntoCss for Pseudo {
+    fn into_css(self) -> String {
+        match self {
+            Self::Class(class) => format!(":{}", class.name()),
+            Self::Element(element) => format!("::{}", element.name()),
+        }
+    }
+}
+
+impl From<PseudoClass> for Pseudo {
+    fn from(class: PseudoClass) -> Self {
+        Self::Class(class)
+    }
+}
+
+impl From<PseudoElement> for Pseudo {
+    fn from(element: PseudoElement) -> Self {
+        Self::Element(element)
+    }
+}
+
+/// `==` for strings in const contexts
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css, style::color::NamedColor};
+
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(PseudoClass::from_name("focus-visible"), Some(PseudoClass::FocusVisible));
+        assert_eq!(PseudoClass::from_name("focus_visible"), None);
+        assert_eq!(PseudoElement::from_name("before"), Some(PseudoElement::Before));
+        assert_eq!(PseudoElement::from_name("hover"), None);
+        assert_eq!(Pseudo::from(PseudoClass::FirstOfType).into_css(), ":first-of-type");
+        assert_eq!(Pseudo::from(PseudoElement::FileSelectorButton).into_css(), "::file-selector-button");
+    }
+
+    #[test]
+    fn states_are_separate_rules() {
+        let block = css!{
+            color: NamedColor::Black;
+            &:hover {
+                color: NamedColor::Red;
+                &::before { width: 10; }
+            }
+            &::placeholder { color: NamedColor::Gray; }
+        };
+        assert_eq!(block.to_rules(".a"), [
+            ".a {color: black;}",
+            ".a:hover {color: red;}",
+            ".a:hover::before {width: 10px;}",
+            ".a::placeholder {color: gray;}",
+        ]);
+    }
+}
@@ -1,6 +1,6 @@
-use super::IntoCss;
+use super::{calc::Calc, IntoCss};
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum Length {
     Percent(f64),
 
@@ -21,6 +21,19 @@ pub enum Length {
     /// 1% of viewport’s larger dimension
     Vmax(f64),
 
+    /// 1% of the query container's width
+    Cqw(f64),
+    /// 1% of the query container's height
+    Cqh(f64),
+    /// 1% of the query container's inline size
+    Cqi(f64),
+    /// 1% of the query container's block size
+    Cqb(f64),
+    /// 1% of the query container's smaller dimension
+    Cqmin(f64),
+    /// 1% of the query container's larger dimension
+    Cqmax(f64),
+
     /// centimeters;	1cm = 96px/2.54
     Cm(f64),
     /// millimeters;	1mm = 1/10th of 1cm
@@ -35,6 +48,9 @@ pub enum Length {
     Pt(f64),
     /// pixels;	1px = 1/96th of 1in
     Px(f64),
+
+    /// A `calc()`, `min()`, `max()` or `clamp()` expression
+    Calc(Box<Calc>),
 }
 
 impl IntoCss for Length {
@@ -51,6 +67,13 @@ impl IntoCss for Length {
             Length::Vmin(length) => format!("{length}vmin"),
             Length::Vmax(length) => format!("{length}vmax"),
 
+            Length::Cqw(length) => format!("{length}cqw"),
+            Length::Cqh(length) => format!("{length}cqh"),
+            Length::Cqi(length) => format!("{length}cqi"),
+            Length::Cqb(length) => format!("{length}cqb"),
+            Length::Cqmin(length) => format!("{length}cqmin"),
+            Length::Cqmax(length) => format!("{length}cqmax"),
+
             Length::Cm(length) => format!("{length}cm"),
             Length::Mm(length) => format!("{length}mm"),
             Length::Q(length) => format!("{length}q"),
@@ -58,6 +81,46 @@ impl IntoCss for Length {
             Length::Pc(length) => format!("{length}pc"),
             Length::Pt(length) => format!("{length}pt"),
             Length::Px(length) => format!("{length}px"),
+
+            Length::Calc(calc) => calc.into_css(),
+        }
+    }
+}
+
+/// The constructor of a length unit, e.g. `Length::Px`
+pub type LengthUnit = fn(f64) -> Length;
+
+impl Length {
+    /// Splits a length into its value and unit, returns `None` for calc expressions
+    pub fn split(&self) -> Option<(f64, LengthUnit)> {
+        match *self {
+            Length::Percent(length) => Some((length, Length::Percent)),
+
+            Length::Em(length) => Some((length, Length::Em)),
+            Length::Ex(length) => Some((length, Length::Ex)),
+            Length::Ch(length) => Some((length, Length::Ch)),
+            Length::Rem(length) => Some((length, Length::Rem)),
+            Length::Vw(length) => Some((length, Length::Vw)),
+            Length::Vh(length) => Some((length, Length::Vh)),
+            Length::Vmin(length) => Some((length, Length::Vmin)),
+            Length::Vmax(length) => Some((length, Length::Vmax)),
+
+            Length::Cqw(length) => Some((length, Length::Cqw)),
+            Length::Cqh(length) => Some((length, Length::Cqh)),
+            Length::Cqi(length) => Some((length, Length::Cqi)),
+            Length::Cqb(length) => Some((length, Length::Cqb)),
+            Length::Cqmin(length) => Some((length, Length::Cqmin)),
+            Length::Cqmax(length) => Some((length, Length::Cqmax)),
+
+            Length::Cm(length) => Some((length, Length::Cm)),
+            Length::Mm(length) => Some((length, Length::Mm)),
+            Length::Q(length) => Some((length, Length::Q)),
+            Length::In(length) => Some((length, Length::In)),
+            Length::Pc(length) => Some((length, Length::Pc)),
+            Length::Pt(length) => Some((length, Length::Pt)),
+            Length::Px(length) => Some((length, Length::Px)),
+
+            Length::Calc(_) => None,
         }
     }
 }
@@ -76,8 +139,6 @@ impl From<f64> for Length {
 
 #[derive(Clone, Copy)]
 pub enum Angle {
-    Percent(f64),
-    
     /// Degrees. There are 360 degrees in a full circle.
     Deg(f64),
     /// Gradians, also known as "gons" or "grades". There are 400 gradians in a full circle.
@@ -88,6 +149,17 @@ pub enum Angle {
     Turn(f64),
 }
 
+impl IntoCss for Angle {
+    fn into_css(self) -> String {
+        match self {
+            Self::Deg(angle) => format!("{angle}deg"),
+            Self::Grad(angle) => format!("{angle}grad"),
+            Self::Rad(angle) => format!("{angle}rad"),
+            Self::Turn(angle) => format!("{angle}turn"),
+        }
+    }
+}
+
 impl From<i64> for Angle {
     fn from(size: i64) -> Self {
         Self::Deg(size as f64)
@@ -96,7 +168,43 @@ impl From<i64> for Angle {
 
 impl From<f64> for Angle {
     fn from(size: f64) -> Self {
-        Self::Rad(size * 100.0)
+        Self::Rad(size)
+    }
+}
+
+impl Angle {
+    /// The size of the angle in turns
+    pub fn to_turn(self) -> f64 {
+        match self {
+            Self::Deg(angle) => angle / 360.0,
+            Self::Grad(angle) => angle / 400.0,
+            Self::Rad(angle) => angle / std::f64::consts::TAU,
+            Self::Turn(angle) => angle,
+        }
+    }
+
+    /// The size of the angle in degrees
+    pub fn to_deg(self) -> f64 {
+        match self {
+            Self::Deg(angle) => angle,
+            x => x.to_turn() * 360.0,
+        }
+    }
+
+    /// The size of the angle in gradians
+    pub fn to_grad(self) -> f64 {
+        match self {
+            Self::Grad(angle) => angle,
+            x => x.to_turn() * 400.0,
+        }
+    }
+
+    /// The size of the angle in radians
+    pub fn to_rad(self) -> f64 {
+        match self {
+            Self::Rad(angle) => angle,
+            x => x.to_turn() * std::f64::consts::TAU,
+        }
     }
 }
 
@@ -137,9 +245,78 @@ impl From<f64> for Duration {
 
 #[derive(Clone, Copy)]
 pub enum Frequency {
-    Percent(f64),
     /// Hertz. It represents the number of occurrences per second.
     Hz(f64),
     /// KiloHertz. A kiloHertz is 1000 Hertz.
     KHz(f64),
-}
\ No newline at end of file
+}
+
+impl IntoCss for Frequency {
+    fn into_css(self) -> String {
+        match self {
+            Self::Hz(frequency) => format!("{frequency}Hz"),
+            Self::KHz(frequency) => format!("{frequency}kHz"),
+        }
+    }
+}
+
+impl From<i64> for Frequency {
+    fn from(size: i64) -> Self {
+        Self::Hz(size as f64)
+    }
+}
+
+impl From<f64> for Frequency {
+    fn from(size: f64) -> Self {
+        Self::Hz(size)
+    }
+}
+
+impl Frequency {
+    /// The frequency in Hertz
+    pub fn to_hz(self) -> f64 {
+        match self {
+            Self::Hz(frequency) => frequency,
+            Self::KHz(frequency) => frequency * 1000.0,
+        }
+    }
+
+    /// The frequency in kiloHertz
+    pub fn to_khz(self) -> f64 {
+        self.to_hz() / 1000.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn angle_conversions() {
+        assert_eq!(Angle::Deg(90.0).into_css(), "90deg");
+        assert_eq!(Angle::Turn(0.5).into_css(), "0.5turn");
+        assert!(close(Angle::Deg(90.0).to_turn(), 0.25));
+        assert!(close(Angle::Grad(200.0).to_deg(), 180.0));
+        assert!(close(Angle::Turn(1.0).to_rad(), std::f64::consts::TAU));
+        assert!(close(Angle::Rad(std::f64::consts::PI).to_grad(), 200.0));
+        assert!(close(Angle::from(45).to_deg(), 45.0));
+    }
+
+    #[test]
+    fn frequency_conversions() {
+        assert_eq!(Frequency::KHz(1.5).into_css(), "1.5kHz");
+        assert_eq!(Frequency::from(440).into_css(), "440Hz");
+        assert!(close(Frequency::KHz(1.5).to_hz(), 1500.0));
+        assert!(close(Frequency::Hz(250.0).to_khz(), 0.25));
+    }
+
+    #[test]
+    fn numbers_as_lengths() {
+        assert_eq!(Length::from(12).into_css(), "12px");
+        assert_eq!(Length::from(0.5).into_css(), "50%");
+    }
+}
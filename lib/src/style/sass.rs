@@ -0,0 +1,183 @@
+use super::{CssBlock, IntoCss};
+
+/// How nested rules are written
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Nesting {
+    /// Every rule with its full selector, e.g. `.card > .title {...}`
+    Flatten,
+    /// Native css nesting, e.g. `.card {& > .title {...}}`
+    Native,
+}
+
+/// How a nested rule is combined with its parent
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Combinator {
+    /// `& .child`
+    Descendant,
+    /// `& > .child`
+    Child,
+    /// `& + .sibling`
+    NextSibling,
+    /// `& ~ .sibling`
+    SubsequentSibling,
+}
+
+impl IntoCss for Combinator {
+    fn into_css(self) -> String {
+        match self {
+            Self::Descendant => " ",
+            Self::Child => " > ",
+            Self::NextSibling => " + ",
+            Self::SubsequentSibling => " ~ ",
+        }.to_string()
+    }
+}
+
+/// A style rule with nested rules.
+///
+/// The query of a nested rule is relative to its parent: `&` is replaced by the parent selector (`&:hover`, `.dark &`),
+/// a query without `&` selects descendants of the parent or starts with a combinator (`> .item`, `+ .item`).
+#[derive(Clone, Default)]
+pub struct SassItem {
+    pub query: String,
+    pub css: CssBlock,
+    pub children: Vec<SassItem>,
+}
+
+impl SassItem {
+    pub fn new(query: &str, css: CssBlock) -> Self {
+        Self { query: query.to_string(), css, children: Vec::new() }
+    }
+
+    /// A rule for `query` combined with the parent by `combinator`
+    pub fn combined(combinator: Combinator, query: &str, css: CssBlock) -> Self {
+        Self::new(&format!("&{}{query}", combinator.into_css()), css)
+    }
+
+    /// Adds a nested rule
+    pub fn child(mut self, child: SassItem) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// The rules of the item and its children, the query of the root is used as it is
+    pub fn to_rules(&self, nesting: Nesting) -> Vec<String> {
+        match nesting {
+            Nesting::Flatten => self.flatten(&self.query),
+            Nesting::Native => vec![self.native(&self.query)],
+        }
+    }
+
+    fn flatten(&self, selector: &str) -> Vec<String> {
+        let mut rules = self.css.to_rules(selector);
+        for child in &self.children {
+            rules.extend(child.flatten(&resolve(selector, &child.query)));
+        }
+        rules
+    }
+
+    fn native(&self, selector: &str) -> String {
+        let mut body = self.css.to_string();
+        for (pseudo, block) in &self.css.states {
+            body.push_str(&SassItem::new(&format!("&{}", pseudo.into_css()), block.clone()).native_nested());
+        }
+        for (query, block) in &self.css.media {
+            body.push_str(&format!("@media {} {{{}}}", query.clone().into_css(), SassItem::new("&", block.clone()).native("&")));
+        }
+        for (query, block) in &self.css.container_queries {
+            body.push_str(&format!("@container {} {{{}}}", query.clone().into_css(), SassItem::new("&", block.clone()).native("&")));
+        }
+        for child in &self.children {
+            body.push_str(&child.native_nested());
+        }
+        format!("{selector} {{{body}}}")
+    }
+
+    /// The rule nested in its parent, with an explicit `&` so it is valid in every browser that supports nesting
+    fn native_nested(&self) -> String {
+        let query = self.query.trim();
+        if query.contains('&') {
+            self.native(query)
+        } else {
+            self.native(&split_selector_list(query).iter().map(|query| format!("& {query}")).collect::<Vec<_>>().join(", "))
+        }
+    }
+}
+
+/// Flattened rules
+impl IntoCss for SassItem {
+    fn into_css(self) -> String {
+        self.to_rules(Nesting::Flatten).join("\n")
+    }
+}
+
+/// The full selector of `query` nested in `parent`, for every combination of the selector lists
+fn resolve(parent: &str, query: &str) -> String {
+    let mut selectors = Vec::new();
+    for parent in split_selector_list(parent) {
+        for query in split_selector_list(query) {
+            selectors.push(if query.contains('&') {
+                query.replace('&', parent)
+            } else {
+                format!("{parent} {query}")
+            });
+        }
+    }
+    selectors.join(", ")
+}
+
+/// Splits a selector list at the commas that are not inside parentheses, e.g. of `:is(a, b)`
+pub(crate) fn split_selector_list(selectors: &str) -> Vec<&str> {
+    let mut list = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in selectors.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                list.push(selectors[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    list.push(selectors[start..].trim());
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css, style::color::NamedColor};
+
+    use super::*;
+
+    fn card() -> SassItem {
+        SassItem::new(".card", css!{ width: 100; })
+            .child(SassItem::combined(Combinator::Child, ".title", css!{ color: NamedColor::Red; }))
+            .child(SassItem::new(".dark &", css!{ color: NamedColor::White; }))
+    }
+
+    #[test]
+    fn flatten() {
+        assert_eq!(card().to_rules(Nesting::Flatten), [
+            ".card {width: 100px;}",
+            ".card > .title {color: red;}",
+            ".dark .card {color: white;}",
+        ]);
+    }
+
+    #[test]
+    fn native() {
+        assert_eq!(card().to_rules(Nesting::Native), [".card {width: 100px;& > .title {color: red;}.dark & {color: white;}}"]);
+        let item = SassItem::new(".a", CssBlock::default()).child(SassItem::new("p, span", css!{ width: 1; }));
+        assert_eq!(item.to_rules(Nesting::Native), [".a {& p, & span {width: 1px;}}"]);
+    }
+
+    #[test]
+    fn selector_lists_are_combined() {
+        let item = SassItem::new(".a, .b", CssBlock::default())
+            .child(SassItem::new("&:hover, :is(p, span)", css!{ width: 1; }));
+        assert_eq!(item.to_rules(Nesting::Flatten)[1], ".a:hover, .a :is(p, span), .b:hover, .b :is(p, span) {width: 1px;}");
+        assert_eq!(split_selector_list("a, [title=\"x\"], :is(b, c)"), ["a", "[title=\"x\"]", ":is(b, c)"]);
+    }
+}
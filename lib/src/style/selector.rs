@@ -0,0 +1,443 @@
+use std::fmt::Display;
+
+use super::{pseudo::{Pseudo, PseudoClass, PseudoElement}, sass::Combinator, CssBlock, IntoCss};
+
+/// A typed css selector, identifiers and attribute values are escaped when it is serialized
+#[derive(Clone, Debug, PartialEq)]
+pub enum Selector {
+    /// `*`
+    Universal,
+    /// A type selector, e.g. `div`
+    Type(String),
+    Class(String),
+    Id(String),
+    Attribute(Attribute),
+    Pseudo(Pseudo),
+    /// `:nth-child(An+B of S)`
+    NthChild(Nth, Option<Box<Selector>>),
+    /// `:nth-last-child(An+B of S)`
+    NthLastChild(Nth, Option<Box<Selector>>),
+    NthOfType(Nth),
+    NthLastOfType(Nth),
+    /// `:is()`, takes the specificity of its most specific argument
+    Is(Vec<Selector>),
+    /// `:where()`, has no specificity
+    Where(Vec<Selector>),
+    Not(Vec<Selector>),
+    /// `:has()`, the arguments are relative to the element, e.g. `Selector::Relative(Combinator::Child, ...)` for `> img`
+    Has(Vec<Selector>),
+    /// A selector relative to an element that is not written, e.g. `> img` in `:has(> img)`
+    Relative(Combinator, Box<Selector>),
+    /// Selectors that all apply to the same element, e.g. `a.active:hover`
+    Compound(Vec<Selector>),
+    Combined(Box<Selector>, Combinator, Box<Selector>),
+    /// A selector list, e.g. `h1, h2`
+    List(Vec<Selector>),
+    /// `&`, the parent selector in nested rules
+    Parent,
+}
+
+impl Selector {
+    pub fn tag(name: &str) -> Self {
+        Self::Type(name.to_string())
+    }
+
+    pub fn class(name: &str) -> Self {
+        Self::Class(name.to_string())
+    }
+
+    pub fn id(name: &str) -> Self {
+        Self::Id(name.to_string())
+    }
+
+    /// `[name]`
+    pub fn attribute(name: &str) -> Self {
+        Self::Attribute(Attribute { name: name.to_string(), matcher: None, case_insensitive: false })
+    }
+
+    /// `[name="value"]`
+    pub fn attribute_eq(name: &str, value: &str) -> Self {
+        Self::attribute_matches(name, AttributeMatcher::Equals, value)
+    }
+
+    pub fn attribute_matches(name: &str, matcher: AttributeMatcher, value: &str) -> Self {
+        Self::Attribute(Attribute { name: name.to_string(), matcher: Some((matcher, value.to_string())), case_insensitive: false })
+    }
+
+    pub fn is(selectors: Vec<Selector>) -> Self {
+        Self::Is(selectors)
+    }
+
+    pub fn where_(selectors: Vec<Selector>) -> Self {
+        Self::Where(selectors)
+    }
+
+    pub fn not(selectors: Vec<Selector>) -> Self {
+        Self::Not(selectors)
+    }
+
+    pub fn has(selectors: Vec<Selector>) -> Self {
+        Self::Has(selectors)
+    }
+
+    /// Both selectors apply to the same element, e.g. `a` and `.active` to `a.active`.
+    /// The type selector is written first wherever it was added, selector lists and combined selectors are wrapped in `:is()`.
+    pub fn and(self, other: impl Into<Selector>) -> Self {
+        let mut selectors = match self {
+            Self::Compound(selectors) => selectors,
+            selector => vec![selector],
+        };
+        match other.into() {
+            Self::Compound(others) => selectors.extend(others),
+            other => selectors.push(other),
+        }
+        Self::Compound(selectors)
+    }
+
+    /// Adds a pseudo-class or pseudo-element
+    pub fn pseudo(self, pseudo: impl Into<Pseudo>) -> Self {
+        self.and(Self::Pseudo(pseudo.into()))
+    }
+
+    /// `self` and `other` combined by `combinator`, selector lists are wrapped in `:is()`
+    pub fn combine(self, combinator: Combinator, other: impl Into<Selector>) -> Self {
+        Self::Combined(Box::new(self), combinator, Box::new(other.into()))
+    }
+
+    /// `self other`
+    pub fn descendant(self, other: impl Into<Selector>) -> Self {
+        self.combine(Combinator::Descendant, other)
+    }
+
+    /// `self > other`
+    pub fn child(self, other: impl Into<Selector>) -> Self {
+        self.combine(Combinator::Child, other)
+    }
+
+    /// `self + other`
+    pub fn next_sibling(self, other: impl Into<Selector>) -> Self {
+        self.combine(Combinator::NextSibling, other)
+    }
+
+    /// `self ~ other`
+    pub fn subsequent_sibling(self, other: impl Into<Selector>) -> Self {
+        self.combine(Combinator::SubsequentSibling, other)
+    }
+
+    /// `self, other`
+    pub fn or(self, other: impl Into<Selector>) -> Self {
+        let other = other.into();
+        match self {
+            Self::List(mut selectors) => {
+                selectors.push(other);
+                Self::List(selectors)
+            },
+            _ => Self::List(vec![self, other]),
+        }
+    }
+
+    /// The rules of `block` for this selector, see `CssBlock::to_rules`
+    pub fn rule(&self, block: &CssBlock) -> String {
+        block.to_rules(&self.to_string()).join("\n")
+    }
+
+    /// The specificity of the selector, the highest one of a selector list
+    pub fn specificity(&self) -> Specificity {
+        let max = |selectors: &[Selector]| selectors.iter().map(Selector::specificity).max().unwrap_or_default();
+        match self {
+            Self::Universal | Self::Parent | Self::Where(_) => Specificity::default(),
+            Self::Type(_) | Self::Pseudo(Pseudo::Element(_)) => Specificity(0, 0, 1),
+            Self::Class(_) | Self::Attribute(_) | Self::Pseudo(Pseudo::Class(_)) | Self::NthOfType(_) | Self::NthLastOfType(_) => Specificity(0, 1, 0),
+            Self::Id(_) => Specificity(1, 0, 0),
+            Self::NthChild(_, of) | Self::NthLastChild(_, of) => {
+                Specificity(0, 1, 0) + of.as_ref().map(|of| of.specificity()).unwrap_or_default()
+            },
+            Self::Is(selectors) | Self::Not(selectors) | Self::Has(selectors) | Self::List(selectors) => max(selectors),
+            Self::Relative(_, selector) => selector.specificity(),
+            Self::Compound(selectors) => selectors.iter().map(Selector::specificity).fold(Specificity::default(), |a, b| a + b),
+            Self::Combined(a, _, b) => a.specificity() + b.specificity(),
+        }
+    }
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let list = |selectors: &[Selector]| selectors.iter().map(Selector::to_string).collect::<Vec<_>>().join(", ");
+        let of = |of: &Option<Box<Selector>>| of.as_ref().map(|of| format!(" of {of}")).unwrap_or_default();
+        match self {
+            Self::Universal => write!(f, "*"),
+            Self::Type(name) => write!(f, "{}", escape_identifier(name)),
+            Self::Class(name) => write!(f, ".{}", escape_identifier(name)),
+            Self::Id(name) => write!(f, "#{}", escape_identifier(name)),
+            Self::Attribute(attribute) => write!(f, "{}", attribute.clone().into_css()),
+            Self::Pseudo(pseudo) => write!(f, "{}", pseudo.into_css()),
+            Self::NthChild(nth, selector) => write!(f, ":nth-child({}{})", nth.into_css(), of(selector)),
+            Self::NthLastChild(nth, selector) => write!(f, ":nth-last-child({}{})", nth.into_css(), of(selector)),
+            Self::NthOfType(nth) => write!(f, ":nth-of-type({})", nth.into_css()),
+            Self::NthLastOfType(nth) => write!(f, ":nth-last-of-type({})", nth.into_css()),
+            Self::Is(selectors) => write!(f, ":is({})", list(selectors)),
+            Self::Where(selectors) => write!(f, ":where({})", list(selectors)),
+            Self::Not(selectors) => write!(f, ":not({})", list(selectors)),
+            Self::Has(selectors) => write!(f, ":has({})", list(selectors)),
+            Self::Relative(combinator, selector) => write!(f, "{}{selector}", combinator.into_css().trim_start()),
+            Self::Compound(selectors) => {
+                // a type selector has to come first and a pseudo-element last
+                let mut selectors: Vec<_> = selectors.iter().collect();
+                selectors.sort_by_key(|selector| match selector {
+                    Self::Type(_) | Self::Universal => 0,
+                    Self::Pseudo(Pseudo::Element(_)) => 2,
+                    _ => 1,
+                });
+                for selector in selectors {
+                    match selector {
+                        Self::List(_) | Self::Combined(..) => write!(f, ":is({selector})")?,
+                        selector => write!(f, "{selector}")?,
+                    }
+                }
+                Ok(())
+            },
+            Self::Combined(a, combinator, b) => {
+                match **a {
+                    Self::List(_) => write!(f, ":is({a})")?,
+                    _ => write!(f, "{a}")?,
+                }
+                f.write_str(&combinator.into_css())?;
+                // the combinators are left-associative, a combined selector on the right is grouped
+                match **b {
+                    Self::List(_) | Self::Combined(..) => write!(f, ":is({b})"),
+                    _ => write!(f, "{b}"),
+                }
+            },
+            Self::List(selectors) => write!(f, "{}", list(selectors)),
+            Self::Parent => write!(f, "&"),
+        }
+    }
+}
+
+impl IntoCss for Selector {
+    fn into_css(self) -> String {
+        self.to_string()
+    }
+}
+
+impl From<PseudoClass> for Selector {
+    fn from(class: PseudoClass) -> Self {
+        Self::Pseudo(class.into())
+    }
+}
+
+impl From<PseudoElement> for Selector {
+    fn from(element: PseudoElement) -> Self {
+        Self::Pseudo(element.into())
+    }
+}
+
+/// `[name]` or `[name <matcher> "value" i]`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub matcher: Option<(AttributeMatcher, String)>,
+    /// Whether the value is compared ignoring ASCII case (`i`)
+    pub case_insensitive: bool,
+}
+
+impl IntoCss for Attribute {
+    fn into_css(self) -> String {
+        let name = escape_identifier(&self.name);
+        match self.matcher {
+            Some((matcher, value)) => {
+                let flag = if self.case_insensitive { " i" } else { "" };
+                format!("[{name}{}{}{flag}]", matcher.into_css(), escape_string(&value))
+            },
+            None => format!("[{name}]"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttributeMatcher {
+    /// `=`
+    Equals,
+    /// `~=`, one of the whitespace separated words
+    Includes,
+    /// `|=`, the value or the value followed by `-`
+    DashMatch,
+    /// `^=`
+    Prefix,
+    /// `$=`
+    Suffix,
+    /// `*=`
+    Substring,
+}
+
+impl IntoCss for AttributeMatcher {
+    fn into_css(self) -> String {
+        match self {
+            Self::Equals => "=",
+            Self::Includes => "~=",
+            Self::DashMatch => "|=",
+            Self::Prefix => "^=",
+            Self::Suffix => "$=",
+            Self::Substring => "*=",
+        }.to_string()
+    }
+}
+
+/// The `An+B` argument of the `:nth-*` pseudo-classes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Nth {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl Nth {
+    pub const ODD: Nth = Nth { a: 2, b: 1 };
+    pub const EVEN: Nth = Nth { a: 2, b: 0 };
+
+    pub fn new(a: i32, b: i32) -> Self {
+        Self { a, b }
+    }
+
+    /// Only the `b`th element
+    pub fn index(b: i32) -> Self {
+        Self { a: 0, b }
+    }
+}
+
+impl IntoCss for Nth {
+    fn into_css(self) -> String {
+        let a = match self.a {
+            0 => return self.b.to_string(),
+            1 => "n".to_string(),
+            -1 => "-n".to_string(),
+            a => format!("{a}n"),
+        };
+        match self.b {
+            0 => a,
+            b if b > 0 => format!("{a}+{b}"),
+            b => format!("{a}{b}"),
+        }
+    }
+}
+
+/// The specificity (ids, classes, types) of a selector, compared in that order
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity(pub u32, pub u32, pub u32);
+
+impl std::ops::Add for Specificity {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+/// Serializes an identifier as specified in https://drafts.csswg.org/cssom/#serialize-an-identifier
+pub fn escape_identifier(identifier: &str) -> String {
+    let mut escaped = String::new();
+    let chars: Vec<char> = identifier.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '\0' => escaped.push('\u{FFFD}'),
+            '\u{1}'..='\u{1F}' | '\u{7F}' => escaped.push_str(&format!("\\{:x} ", c as u32)),
+            '0'..='9' if i == 0 || (i == 1 && chars[0] == '-') => escaped.push_str(&format!("\\{:x} ", c as u32)),
+            '-' if i == 0 && chars.len() == 1 => escaped.push_str("\\-"),
+            c if c as u32 >= 0x80 || c == '-' || c == '_' || c.is_ascii_alphanumeric() => escaped.push(c),
+            c => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+        }
+    }
+    escaped
+}
+
+/// Serializes a string as specified in https://drafts.csswg.org/cssom/#serialize-a-string
+pub fn escape_string(string: &str) -> String {
+    let mut escaped = String::from('"');
+    for c in string.chars() {
+        match c {
+            '\0' => escaped.push('\u{FFFD}'),
+            '\u{1}'..='\u{1F}' | '\u{7F}' => escaped.push_str(&format!("\\{:x} ", c as u32)),
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        let selector = Selector::tag("button").and(Selector::class("primary")).pseudo(PseudoClass::Hover)
+            .or(Selector::attribute_eq("data-state", "open").child(Selector::NthChild(Nth::ODD, None)));
+        assert_eq!(selector.to_string(), "button.primary:hover, [data-state=\"open\"] > :nth-child(2n+1)");
+        assert_eq!(Selector::has(vec![Selector::Relative(Combinator::Child, Box::new(Selector::tag("img")))]).to_string(), ":has(> img)");
+        assert_eq!(Selector::NthLastChild(Nth::new(-1, 3), Some(Box::new(Selector::class("x")))).to_string(), ":nth-last-child(-n+3 of .x)");
+        assert_eq!(Selector::Parent.and(PseudoElement::Before).to_string(), "&::before");
+    }
+
+    #[test]
+    fn type_selectors_come_first() {
+        assert_eq!(Selector::class("x").and(Selector::tag("div")).to_string(), "div.x");
+        assert_eq!(Selector::id("a").pseudo(PseudoClass::Hover).and(Selector::Universal).to_string(), "*#a:hover");
+        let nested = Selector::class("x").and(Selector::tag("p").and(Selector::class("y")));
+        assert_eq!(nested, Selector::Compound(vec![Selector::class("x"), Selector::tag("p"), Selector::class("y")]));
+        assert_eq!(nested.to_string(), "p.x.y");
+    }
+
+    #[test]
+    fn pseudo_elements_come_last() {
+        assert_eq!(Selector::tag("p").and(PseudoElement::Before).and(Selector::class("x")).to_string(), "p.x::before");
+        assert_eq!(Selector::Parent.and(PseudoElement::Marker).pseudo(PseudoClass::Hover).to_string(), "&:hover::marker");
+    }
+
+    #[test]
+    fn lists_and_combined_selectors_are_grouped() {
+        let headings = Selector::tag("h1").or(Selector::tag("h2"));
+        let child = headings.clone().child(Selector::tag("span"));
+        assert_eq!(child.to_string(), ":is(h1, h2) > span");
+        assert_eq!(child.specificity(), Specificity(0, 0, 2));
+        assert_eq!(headings.clone().and(Selector::class("x")).to_string(), ":is(h1, h2).x");
+        assert_eq!(Selector::class("a").descendant(headings).to_string(), ".a :is(h1, h2)");
+
+        let right = Selector::tag("a").child(Selector::tag("b").descendant(Selector::tag("c")));
+        assert_eq!(right.to_string(), "a > :is(b c)");
+        let left = Selector::tag("a").child(Selector::tag("b")).descendant(Selector::tag("c"));
+        assert_eq!(left.to_string(), "a > b c");
+    }
+
+    #[test]
+    fn specificity() {
+        assert_eq!(Selector::Universal.specificity(), Specificity(0, 0, 0));
+        assert_eq!(Selector::tag("a").and(Selector::class("b")).pseudo(PseudoClass::Hover).specificity(), Specificity(0, 2, 1));
+        assert_eq!(Selector::id("a").child(Selector::tag("p").pseudo(PseudoElement::Before)).specificity(), Specificity(1, 0, 2));
+        assert_eq!(Selector::is(vec![Selector::id("a"), Selector::class("b")]).specificity(), Specificity(1, 0, 0));
+        assert_eq!(Selector::where_(vec![Selector::id("a")]).specificity(), Specificity(0, 0, 0));
+        assert_eq!(Selector::NthChild(Nth::EVEN, Some(Box::new(Selector::class("x")))).specificity(), Specificity(0, 2, 0));
+        assert_eq!(Selector::class("a").or(Selector::id("b")).specificity(), Specificity(1, 0, 0));
+        assert!(Specificity(0, 1, 0) > Specificity(0, 0, 9));
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape_identifier("1a"), "\\31 a");
+        assert_eq!(escape_identifier("-"), "\\-");
+        assert_eq!(escape_identifier("a.b:c"), "a\\.b\\:c");
+        assert_eq!(escape_string("a\"b\\"), "\"a\\\"b\\\\\"");
+        let attribute = Attribute { case_insensitive: true, ..match Selector::attribute_matches("lang", AttributeMatcher::DashMatch, "en") {
+            Selector::Attribute(attribute) => attribute,
+            _ => unreachable!(),
+        } };
+        assert_eq!(attribute.into_css(), "[lang|=\"en\" i]");
+        assert_eq!(Nth::index(-2).into_css(), "-2");
+        assert_eq!(Nth::new(3, -1).into_css(), "3n-1");
+    }
+}
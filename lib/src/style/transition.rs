@@ -0,0 +1,66 @@
+use super::{easing::{timing_parts, Easing}, quantities::Duration, IntoCss, Property};
+
+/// One transition of the `transition` shorthand, the parts that are not set keep their initial value
+#[derive(Clone)]
+pub struct Transition {
+    pub property: Property,
+    pub duration: Option<Duration>,
+    pub easing: Option<Easing>,
+    pub delay: Option<Duration>,
+}
+
+impl Transition {
+    pub fn new(property: Property, duration: impl Into<Duration>) -> Self {
+        Self { property, duration: Some(duration.into()), easing: None, delay: None }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    pub fn delay(mut self, delay: impl Into<Duration>) -> Self {
+        self.delay = Some(delay.into());
+        self
+    }
+}
+
+impl IntoCss for Transition {
+    fn into_css(self) -> String {
+        let mut parts = vec![self.property.into_css()];
+        parts.extend(timing_parts(self.duration, self.easing, self.delay));
+        parts.retain(|part| !part.is_empty());
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::css;
+
+    use super::*;
+
+    #[test]
+    fn shorthand() {
+        assert_eq!(Transition::new(Property::width, Duration::Ms(200.0)).into_css(), "width 200ms");
+        let transition = Transition::new(Property::opacity, Duration::S(1.0)).easing(Easing::EaseIn).delay(Duration::Ms(50.0));
+        assert_eq!(transition.into_css(), "opacity 1s ease-in 50ms");
+        let mut delayed = Transition::new(Property::opacity, Duration::S(1.0)).delay(Duration::S(2.0));
+        delayed.duration = None;
+        assert_eq!(delayed.into_css(), "opacity 0s 2s");
+    }
+
+    #[test]
+    fn lists() {
+        let button = css!{
+            transition: vec![
+                Transition::new(Property::opacity, Duration::Ms(200.0)).easing(Easing::EaseOut),
+                Transition::new(Property::transform, Duration::Ms(300.0)).delay(Duration::Ms(50.0)),
+            ];
+            transition_property: vec![Property::background_color, Property::border_color];
+        };
+        let css = button.to_string();
+        assert!(css.contains("transition: opacity 200ms ease-out, transform 300ms 50ms;"), "{}", css);
+        assert!(css.contains("transition-property: background-color, border-color;"), "{}", css);
+    }
+}
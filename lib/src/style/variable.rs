@@ -0,0 +1,74 @@
+use super::IntoCss;
+
+/// A `var()` reference to a custom property.
+/// 
+/// The fallback has the type of the field the variable is used in, so `var(--gap, red)` can not be assigned to a length.
+#[derive(Clone, Debug)]
+pub struct Var<T: IntoCss> {
+    /// The name of the custom property including the leading `--`
+    pub name: String,
+    pub fallback: Option<Box<T>>,
+}
+
+impl<T: IntoCss> Var<T> {
+    /// A reference to the custom property `name`, the leading `--` is optional
+    pub fn new(name: &str) -> Self {
+        Self { name: property_name(name), fallback: None }
+    }
+
+    pub fn with_fallback(name: &str, fallback: impl Into<T>) -> Self {
+        Self { name: property_name(name), fallback: Some(Box::new(fallback.into())) }
+    }
+}
+
+impl<T: IntoCss> IntoCss for Var<T> {
+    fn into_css(self) -> String {
+        match self.fallback {
+            Some(fallback) => format!("var({}, {})", self.name, fallback.into_css()),
+            None => format!("var({})", self.name),
+        }
+    }
+}
+
+/// Shorthand for `Var::new`
+pub fn var<T: IntoCss>(name: &str) -> Var<T> {
+    Var::new(name)
+}
+
+/// Prefixes `name` with `--` unless it already is
+pub(crate) fn property_name(name: &str) -> String {
+    if name.starts_with("--") {
+        name.to_string()
+    } else {
+        format!("--{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css, style::{color::{Color, NamedColor}, quantities::Length}};
+
+    use super::*;
+
+    #[test]
+    fn references() {
+        assert_eq!(var::<Length>("gap").into_css(), "var(--gap)");
+        assert_eq!(Var::<Length>::with_fallback("--gap", 12).into_css(), "var(--gap, 12px)");
+    }
+
+    #[test]
+    fn custom_properties_in_blocks() {
+        let block = css!{
+            --brand-color: Color::from(NamedColor::Navy);
+            --spacing: Length::Px(4.0) Length::Rem(1.0);
+            width: var(--content-width, 0.5);
+            color: var(--brand-color);
+        };
+        assert_eq!(block.variables, vec![
+            ("--brand-color".to_string(), "navy".to_string()),
+            ("--spacing".to_string(), "4px 1rem".to_string()),
+        ]);
+        assert_eq!(block.width.into_css(), "var(--content-width, 50%)");
+        assert_eq!(block.color.into_css(), "var(--brand-color)");
+    }
+}
@@ -0,0 +1,177 @@
+//! `@font-face` rules to load fonts.
+
+use crate::{css_enum, style::{font::{FontFamily, FontStretch, FontStyle, FontWeight}, selector::escape_string, url::Url, IntoCss}};
+
+use super::StyleSheet;
+
+css_enum!(FontFormat;
+    Woff | Woff2 | Truetype | Opentype | EmbeddedOpentype "embedded-opentype" | Collection | Svg
+);
+css_enum!(FontDisplay; Auto | Block | Swap | Fallback | Optional);
+
+/// A source of a font face, the browser uses the first one it supports
+#[derive(Clone)]
+pub enum FontSource {
+    /// A font file with an optional `format()` hint, so the browser can skip unsupported formats without loading them
+    Url(Url, Option<FontFormat>),
+    /// A font installed on the device
+    Local(String),
+}
+
+impl IntoCss for FontSource {
+    fn into_css(self) -> String {
+        match self {
+            Self::Url(url, Some(format)) => format!("{} format({})", url.into_css(), escape_string(&format.into_css())),
+            Self::Url(url, None) => url.into_css(),
+            Self::Local(name) => format!("local({})", escape_string(&name)),
+        }
+    }
+}
+
+impl From<Url> for FontSource {
+    fn from(url: Url) -> Self {
+        Self::Url(url, None)
+    }
+}
+
+impl From<(Url, FontFormat)> for FontSource {
+    fn from((url, format): (Url, FontFormat)) -> Self {
+        Self::Url(url, Some(format))
+    }
+}
+
+/// An `@font-face` rule that makes a font available under `family`
+#[derive(Clone)]
+pub struct FontFace {
+    pub family: String,
+    pub src: Vec<FontSource>,
+    pub style: Option<FontStyle>,
+    /// The weight, or the lowest weight of a variable font
+    pub weight: Option<FontWeight>,
+    /// The highest weight of a variable font
+    pub max_weight: Option<FontWeight>,
+    pub stretch: Option<FontStretch>,
+    pub display: Option<FontDisplay>,
+    /// The characters the font is used for, e.g. `U+0000-00FF, U+0131`
+    pub unicode_range: Option<String>,
+}
+
+impl FontFace {
+    pub fn new(family: &str) -> Self {
+        Self {
+            family: family.to_string(),
+            src: Vec::new(),
+            style: None,
+            weight: None,
+            max_weight: None,
+            stretch: None,
+            display: None,
+            unicode_range: None,
+        }
+    }
+
+    /// Adds a source, after the ones that are preferred
+    pub fn src(mut self, source: impl Into<FontSource>) -> Self {
+        self.src.push(source.into());
+        self
+    }
+
+    pub fn style(mut self, style: FontStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn weight(mut self, weight: impl Into<FontWeight>) -> Self {
+        self.weight = Some(weight.into());
+        self
+    }
+
+    /// The weights a variable font supports
+    pub fn weight_range(mut self, min: impl Into<FontWeight>, max: impl Into<FontWeight>) -> Self {
+        self.weight = Some(min.into());
+        self.max_weight = Some(max.into());
+        self
+    }
+
+    pub fn stretch(mut self, stretch: FontStretch) -> Self {
+        self.stretch = Some(stretch);
+        self
+    }
+
+    pub fn display(mut self, display: FontDisplay) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    pub fn unicode_range(mut self, range: &str) -> Self {
+        self.unicode_range = Some(range.to_string());
+        self
+    }
+
+    /// Registers the rule in the stylesheet of the current thread like `CssBlock::class_name` and returns the family to use it with
+    pub fn register(&self) -> FontFamily {
+        StyleSheet::register_rule("yf", self);
+        FontFamily::Name(self.family.clone())
+    }
+}
+
+impl IntoCss for FontFace {
+    fn into_css(self) -> String {
+        let mut descriptors = format!("font-family: {};", escape_string(&self.family));
+        if !self.src.is_empty() {
+            let src: Vec<String> = self.src.into_iter().map(FontSource::into_css).collect();
+            descriptors.push_str(&format!("src: {};", src.join(", ")));
+        }
+        if let Some(style) = self.style {
+            descriptors.push_str(&format!("font-style: {};", style.into_css()));
+        }
+        match (self.weight, self.max_weight) {
+            (Some(min), Some(max)) => descriptors.push_str(&format!("font-weight: {} {};", min.into_css(), max.into_css())),
+            (Some(weight), None) => descriptors.push_str(&format!("font-weight: {};", weight.into_css())),
+            _ => {},
+        }
+        if let Some(stretch) = self.stretch {
+            descriptors.push_str(&format!("font-stretch: {};", stretch.into_css()));
+        }
+        if let Some(display) = self.display {
+            descriptors.push_str(&format!("font-display: {};", display.into_css()));
+        }
+        if let Some(range) = self.unicode_range {
+            descriptors.push_str(&format!("unicode-range: {range};"));
+        }
+        format!("@font-face {{{descriptors}}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stylesheet::with_global;
+
+    use super::*;
+
+    #[test]
+    fn descriptors() {
+        let face = FontFace::new("Brand Sans")
+            .src((Url::parse("https://example.com/brand.woff2").unwrap(), FontFormat::Woff2))
+            .src(FontSource::Local("Brand \"Sans\"".into()))
+            .weight_range(100, 900)
+            .style(FontStyle::Oblique(None))
+            .display(FontDisplay::Swap)
+            .unicode_range("U+0000-00FF");
+        assert_eq!(face.into_css(), concat!(
+            "@font-face {font-family: \"Brand Sans\";",
+            "src: url(\"https://example.com/brand.woff2\") format(\"woff2\"), local(\"Brand \\\"Sans\\\"\");",
+            "font-style: oblique;font-weight: 100 900;font-display: swap;unicode-range: U+0000-00FF;}",
+        ));
+    }
+
+    #[test]
+    fn register_returns_the_family() {
+        let face = FontFace::new("Mono").src(FontSource::Local("Mono".into()));
+        assert_eq!(face.register().into_css(), "\"Mono\"");
+        let css = with_global(|sheet| sheet.to_string());
+        assert_eq!(css.matches("@font-face {font-family: \"Mono\";").count(), 1);
+        face.register();
+        assert_eq!(with_global(|sheet| sheet.to_string()), css);
+    }
+}
@@ -0,0 +1,215 @@
+//! Inserting rules into a `<style>` element in `document.head` at runtime.
+
+use std::{cell::RefCell, collections::HashSet};
+
+use wasm_bindgen::JsCast;
+use web_sys::{CssStyleSheet, Element, HtmlStyleElement};
+
+use crate::style::CssBlock;
+
+use super::{content_hash, ssr, StyleSheet};
+
+/// Owns a `<style>` element in `document.head` and inserts each entry of rules into it once.
+/// Entries that were rendered on the server (see `StyleCollector::to_style_tag`) are not inserted again.
+///
+/// Without a document (outside of a browser or under node) the rules are dropped, `StyleInjector::text` collects them as text instead.
+pub struct StyleInjector {
+    /// The ids of the entries that were inserted
+    inserted: HashSet<String>,
+    target: Target,
+}
+
+enum Target {
+    Dom(HtmlStyleElement),
+    Text(String),
+    /// There is no document, only the ids are kept
+    None,
+}
+
+impl StyleInjector {
+    /// Appends a new `<style>` element to `document.head`, the rules are dropped if there is no document
+    pub fn new() -> Self {
+        match create_style_element() {
+            Some((element, rendered)) => rendered.iter().fold(
+                Self { inserted: HashSet::new(), target: Target::Dom(element) },
+                |injector, ids| injector.with_rendered(ids),
+            ),
+            None => Self { inserted: HashSet::new(), target: Target::None },
+        }
+    }
+
+    /// An injector that only collects the rules as text
+    pub fn text() -> Self {
+        Self { inserted: HashSet::new(), target: Target::Text(String::new()) }
+    }
+
+    /// Marks the entries listed in the `data-yewcss` attribute `ids` of a `<style>` element rendered on the server as inserted
+    pub fn with_rendered(mut self, ids: &str) -> Self {
+        self.inserted.extend(ids.split_whitespace().map(str::to_string));
+        self
+    }
+
+    /// Inserts the rules of `block` for `selector`, unless the same block was inserted for the selector before.
+    /// Active `StyleCollector`s record the rules either way. Returns whether the rules were new.
+    ///
+    /// The entry id is `yi-` followed by a hash of the rules, so it does not clash with the classes of the stylesheet.
+    pub fn inject(&mut self, selector: &str, block: &CssBlock) -> bool {
+        let rules = block.to_rules(selector);
+        let id = format!("yi-{:016x}", content_hash(|| block.to_rules(selector).concat()));
+        ssr::record(&id, &rules);
+        self.inject_rules(&id, &rules)
+    }
+
+    /// Inserts `rules` as the entry `id` unless an entry with that id was inserted before, returns whether they were new
+    pub fn inject_rules(&mut self, id: &str, rules: &[String]) -> bool {
+        if !self.inserted.insert(id.to_string()) {
+            return false;
+        }
+        match &mut self.target {
+            Target::Dom(element) => {
+                if let Some(sheet) = element.sheet().and_then(|sheet| sheet.dyn_into::<CssStyleSheet>().ok()) {
+                    for rule in rules {
+                        let end = sheet.css_rules().map(|rules| rules.length()).unwrap_or(0);
+                        // the browser rejects rules it can not parse, the other rules are still inserted
+                        let _ = sheet.insert_rule_with_index(rule, end);
+                    }
+                }
+            },
+            Target::Text(text) => {
+                for rule in rules {
+                    text.push_str(rule);
+                    text.push('\n');
+                }
+            },
+            Target::None => {},
+        }
+        true
+    }
+
+    /// Inserts the entries of `sheet` that were not inserted yet
+    pub fn sync(&mut self, sheet: &StyleSheet) {
+        for (id, rules) in sheet.entries() {
+            self.inject_rules(id, rules);
+        }
+    }
+
+    /// Whether the entry `id` was inserted
+    pub fn contains(&self, id: &str) -> bool {
+        self.inserted.contains(id)
+    }
+
+    /// The collected rules of an injector created with `text`
+    pub fn as_text(&self) -> Option<&str> {
+        match &self.target {
+            Target::Text(text) => Some(text),
+            Target::Dom(_) | Target::None => None,
+        }
+    }
+}
+
+impl Default for StyleInjector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The new `<style>` element and the `data-yewcss` attributes of the `<style>` elements rendered on the server
+fn create_style_element() -> Option<(HtmlStyleElement, Vec<String>)> {
+    // the web_sys bindings panic outside of wasm
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let document = web_sys::window()?.document()?;
+
+    let mut rendered = Vec::new();
+    if let Ok(elements) = document.query_selector_all("style[data-yewcss]") {
+        for i in 0..elements.length() {
+            let ids = elements.get(i)
+                .and_then(|node| node.dyn_into::<Element>().ok())
+                .and_then(|element| element.get_attribute("data-yewcss"));
+            rendered.extend(ids);
+        }
+    }
+
+    let element = document.create_element("style").ok()?.dyn_into::<HtmlStyleElement>().ok()?;
+    element.set_attribute("data-yewcss", "").ok()?;
+    document.head()?.append_child(&element).ok()?;
+    Some((element, rendered))
+}
+
+thread_local! {
+    static GLOBAL: RefCell<StyleInjector> = RefCell::new(StyleInjector::new());
+}
+
+/// Runs `f` with the injector of the current thread, which `CssBlock::class_name` inserts the rules of new classes with
+pub fn with_global<R>(f: impl FnOnce(&mut StyleInjector) -> R) -> R {
+    GLOBAL.with(|injector| f(&mut injector.borrow_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    // run with `wasm-pack test --node` on wasm, where there is no document either
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use crate::{css, stylesheet::ClassName};
+
+    use super::*;
+
+    fn rules(rule: &str) -> Vec<String> {
+        vec![rule.to_string()]
+    }
+
+    #[test]
+    fn drops_the_rules_without_a_document() {
+        let mut injector = StyleInjector::new();
+        assert!(injector.inject(".a", &css!{ width: 20; }));
+        assert!(!injector.inject(".a", &css!{ width: 20; }));
+        assert_eq!(injector.as_text(), None);
+    }
+
+    #[test]
+    fn inject_ids_do_not_clash_with_class_names() {
+        let block = css!{ width: 20; };
+        let class_name = ClassName::of(&block);
+        let mut injector = StyleInjector::text();
+        assert!(injector.inject(&format!(".{class_name}"), &block));
+        assert!(!injector.contains(class_name.as_str()));
+        assert!(injector.inject_rules(class_name.as_str(), &rules(".a {}")));
+    }
+
+    #[test]
+    fn rules_are_inserted_once() {
+        let mut injector = StyleInjector::text();
+        assert!(injector.inject_rules("a", &rules(".a {}")));
+        assert!(!injector.inject_rules("a", &rules(".a {}")));
+        assert!(injector.inject(".b", &css!{ width: 20; }));
+        assert!(!injector.inject(".b", &css!{ width: 20; }));
+        assert_eq!(injector.as_text(), Some(".a {}\n.b {width: 20px;}\n"));
+    }
+
+    #[test]
+    fn sync_only_appends_new_entries() {
+        let mut sheet = StyleSheet::new();
+        let mut injector = StyleInjector::text();
+        sheet.insert("a", rules(".a {}"));
+        injector.sync(&sheet);
+        sheet.insert("b", rules(".b {}"));
+        injector.sync(&sheet);
+        injector.sync(&sheet);
+        assert_eq!(injector.as_text(), Some(".a {}\n.b {}\n"));
+        assert!(injector.contains("a") && injector.contains("b"));
+    }
+
+    #[test]
+    fn rendered_entries_are_skipped() {
+        let mut sheet = StyleSheet::new();
+        sheet.insert("a", rules(".a {}"));
+        sheet.insert("b", rules(".b {}"));
+        sheet.insert("c", rules(".c {}"));
+        let mut injector = StyleInjector::text().with_rendered(" a\tc ");
+        assert!(injector.contains("a") && injector.contains("c"));
+        injector.sync(&sheet);
+        assert_eq!(injector.as_text(), Some(".b {}\n"));
+    }
+}
@@ -0,0 +1,254 @@
+//! Stylesheets with generated class names.
+//!
+//! A `CssBlock` is registered under a class name derived from its content, so the same block always gets the same class and its rules are only added once.
+
+use std::{cell::RefCell, collections::HashMap, fmt::Display};
+
+use yew::Classes;
+
+use crate::style::{animation::{AnimationName, Keyframes}, color::{self, ColorFormat}, CssBlock, IntoCss};
+
+pub use self::{injector::StyleInjector, ssr::StyleCollector};
+
+pub mod font_face;
+pub mod injector;
+pub mod ssr;
+
+/// The rules of a stylesheet, grouped in entries that are only added once
+#[derive(Default, Clone, Debug)]
+pub struct StyleSheet {
+    entries: Vec<(String, Vec<String>)>,
+    /// The position of each id in `entries`
+    index: HashMap<String, usize>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `rules` as the entry `id` unless there already is an entry with that id, returns the rules if they were added
+    pub fn insert(&mut self, id: &str, rules: Vec<String>) -> Option<&[String]> {
+        if self.contains(id) {
+            return None;
+        }
+        self.index.insert(id.to_string(), self.entries.len());
+        self.entries.push((id.to_string(), rules));
+        self.entries.last().map(|(_, rules)| rules.as_slice())
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.index.contains_key(id)
+    }
+
+    /// The rules of the entry `id`
+    pub fn get(&self, id: &str) -> Option<&[String]> {
+        self.index.get(id).map(|&position| self.entries[position].1.as_slice())
+    }
+
+    /// The ids and rules of the entries in the order they were added
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.entries.iter().map(|(id, rules)| (id.as_str(), rules.as_slice()))
+    }
+
+    /// Registers an at-rule like `@keyframes` in the stylesheet of the current thread like `CssBlock::class_name`,
+    /// as the entry `prefix` followed by a hash of the rule. Returns the id of the entry.
+    pub fn register_rule<R: IntoCss + Clone>(prefix: &str, rule: &R) -> String {
+        let id = format!("{prefix}-{:016x}", content_hash(|| rule.clone().into_css()));
+        with_global(|sheet| {
+            if let Some(rules) = sheet.insert(&id, vec![rule.clone().into_css()]) {
+                injector::with_global(|injector| injector.inject_rules(&id, rules));
+            }
+            ssr::record(&id, sheet.get(&id).unwrap_or_default());
+        });
+        id
+    }
+
+    /// Registers `block` under a class name derived from its content
+    pub fn add_block(&mut self, block: &CssBlock) -> ClassName {
+        self.add_block_rules(block).0
+    }
+
+    /// Like `add_block`, also returns the rules of the block if they were added
+    fn add_block_rules(&mut self, block: &CssBlock) -> (ClassName, Option<&[String]>) {
+        let class_name = ClassName::of(block);
+        if self.contains(&class_name.0) {
+            return (class_name, None);
+        }
+        let rules = block.to_rules(&format!(".{class_name}"));
+        let added = self.insert(&class_name.0, rules);
+        (class_name, added)
+    }
+}
+
+/// All rules, one per line
+impl Display for StyleSheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rule in self.entries.iter().flat_map(|(_, rules)| rules) {
+            writeln!(f, "{rule}")?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    static GLOBAL: RefCell<StyleSheet> = RefCell::new(StyleSheet::new());
+}
+
+/// Runs `f` with the stylesheet of the current thread, which `CssBlock::class_name` registers blocks in
+pub fn with_global<R>(f: impl FnOnce(&mut StyleSheet) -> R) -> R {
+    GLOBAL.with(|sheet| f(&mut sheet.borrow_mut()))
+}
+
+/// A generated class name, `yc-` followed by a hash of the css
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClassName(String);
+
+impl ClassName {
+    /// The class name of `block`, without registering it
+    pub fn of(block: &CssBlock) -> Self {
+        Self(format!("yc-{:016x}", content_hash(|| block.to_rules("&").concat())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ClassName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<ClassName> for Classes {
+    fn from(class_name: ClassName) -> Self {
+        Classes::from(class_name.0)
+    }
+}
+
+impl From<&ClassName> for Classes {
+    fn from(class_name: &ClassName) -> Self {
+        Classes::from(&class_name.0)
+    }
+}
+
+impl CssBlock {
+    /// Registers the block in the stylesheet of the current thread and returns its class name.
+    /// New rules are inserted into the document by the injector of the current thread and active `StyleCollector`s record the rules.
+    ///
+    /// Equal blocks share one class and rule.
+    pub fn class_name(&self) -> ClassName {
+        with_global(|sheet| {
+            let (class_name, added) = sheet.add_block_rules(self);
+            if let Some(rules) = added {
+                injector::with_global(|injector| injector.inject_rules(class_name.as_str(), rules));
+            }
+            ssr::record(class_name.as_str(), sheet.get(class_name.as_str()).unwrap_or_default());
+            class_name
+        })
+    }
+}
+
+impl Keyframes {
+    /// Registers the `@keyframes` rule in the stylesheet of the current thread like `CssBlock::class_name` and returns its name.
+    ///
+    /// The rule is registered under the name followed by a hash of the frames, so keyframes with the same name and different frames do not replace each other.
+    pub fn register(&self) -> AnimationName {
+        let hash = content_hash(|| self.clone().into_css());
+        let keyframes = Keyframes { name: format!("{}-{hash:016x}", self.name), frames: self.frames.clone() };
+        StyleSheet::register_rule("yk", &keyframes);
+        (&keyframes).into()
+    }
+}
+
+/// The hash of the css returned by `css`, with colors in the functional format so it does not change with `color::set_default_format`
+pub(crate) fn content_hash(css: impl FnOnce() -> String) -> u64 {
+    color::with_format(ColorFormat::Functional, || hash(&css()))
+}
+
+/// 64 bit FNV-1a, stable across builds and platforms unlike `DefaultHasher`
+pub(crate) fn hash(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css, style::color::{set_default_format, Color, ColorFormat}};
+
+    use super::*;
+
+    #[test]
+    fn equal_blocks_share_a_class() {
+        let mut sheet = StyleSheet::new();
+        let a = sheet.add_block(&css!{ width: 20; height: 20; });
+        let b = sheet.add_block(&css!{ width: 20; height: 20; });
+        let c = sheet.add_block(&css!{ width: 30; });
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(sheet.entries().count(), 2);
+        assert_eq!(sheet.insert(c.as_str(), vec![String::new()]), None);
+        assert_eq!(a.as_str(), "yc-1b6db6e62ad277b0");
+        assert_eq!(sheet.to_string().lines().next(), Some(".yc-1b6db6e62ad277b0 {height: 20px;width: 20px;}"));
+    }
+
+    #[test]
+    fn class_name_registers_the_block_once() {
+        let block = css!{ width: 40; &:hover { width: 50; } };
+        let class_name = block.class_name();
+        assert_eq!(class_name, ClassName::of(&block));
+        assert_eq!(block.clone().class_name(), class_name);
+        let entries = with_global(|sheet| sheet.entries().filter(|(id, _)| *id == class_name.as_str()).count());
+        assert_eq!(entries, 1);
+        assert_eq!(with_global(|sheet| sheet.get(class_name.as_str()).map(<[String]>::to_vec)), Some(vec![format!(".{class_name} {{width: 40px;}}"), format!(".{class_name}:hover {{width: 50px;}}")]));
+        let css = with_global(|sheet| sheet.to_string());
+        assert!(css.contains(&format!(".{class_name}:hover {{width: 50px;}}")), "{}", css);
+        assert!(injector::with_global(|injector| injector.contains(class_name.as_str())));
+        assert_eq!(Classes::from(&class_name).to_string(), class_name.as_str());
+    }
+
+    #[test]
+    fn class_names_do_not_depend_on_the_color_format() {
+        let block = css!{ color: Color::RGB(255, 0, 0); };
+        let functional = ClassName::of(&block);
+        set_default_format(ColorFormat::Shortest);
+        let shortest = ClassName::of(&block);
+        let mut sheet = StyleSheet::new();
+        sheet.add_block(&block);
+        set_default_format(ColorFormat::Functional);
+        assert_eq!(functional, shortest);
+        // the rules are still written in the format of the thread
+        assert_eq!(sheet.to_string(), format!(".{functional} {{color: red;}}\n"));
+    }
+
+    #[test]
+    fn keyframes_with_the_same_name_do_not_collide() {
+        let grow = Keyframes::new("spin", vec![(100.0, css!{ width: 1.0; })]);
+        let shrink = Keyframes::new("spin", vec![(100.0, css!{ width: 0; })]);
+        let (a, b) = (grow.register(), shrink.register());
+        assert_ne!(a, b);
+        assert_eq!(grow.register(), a);
+        let name = a.clone().into_css();
+        assert!(name.starts_with("spin-") && name.len() == "spin-".len() + 16, "{}", name);
+        let css = with_global(|sheet| sheet.to_string());
+        assert!(css.contains(&format!("@keyframes {name} {{100% {{width: 100%;}}}}")), "{}", css);
+        assert!(css.contains(&format!("@keyframes {} {{100% {{width: 0px;}}}}", b.into_css())), "{}", css);
+    }
+
+    #[test]
+    fn rules_are_registered_once() {
+        let keyframes = Keyframes::new("fade", vec![(0.0, css!{ width: 0; })]);
+        let id = StyleSheet::register_rule("yt", &keyframes);
+        assert!(id.starts_with("yt-") && id.len() == "yt-".len() + 16, "{}", id);
+        assert_eq!(StyleSheet::register_rule("yt", &keyframes), id);
+        let count = with_global(|sheet| sheet.entries().filter(|(entry, _)| *entry == id).count());
+        assert_eq!(count, 1);
+        assert!(injector::with_global(|injector| injector.contains(&id)));
+    }
+
+    #[test]
+    fn hash_is_fnv_1a() {
+        assert_eq!(hash(""), 0xcbf29ce484222325);
+        assert_eq!(hash("a"), 0xaf63dc4c8601ec8c);
+    }
+}
@@ -0,0 +1,178 @@
+//! Collecting the styles used during a server side render.
+//!
+//! The collector is independent of the renderer, it records everything that is registered on the current thread
+//! while a closure runs or a future that renders the html is polled.
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::StyleSheet;
+
+thread_local! {
+    /// The sheets of the collectors that are currently recording, innermost last
+    static ACTIVE: RefCell<Vec<StyleSheet>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records the rules of every class name and injected block while it is active
+#[derive(Default, Debug)]
+pub struct StyleCollector {
+    sheet: StyleSheet,
+}
+
+impl StyleCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `f` while recording the styles it uses
+    pub fn collect<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        let _recording = Recording::start(self);
+        f()
+    }
+
+    /// Polls `future` while recording the styles it uses
+    pub fn collect_async<F: Future>(&mut self, future: F) -> Collecting<'_, F> {
+        Collecting { collector: self, future: Box::pin(future) }
+    }
+
+    /// The collected rules
+    pub fn sheet(&self) -> &StyleSheet {
+        &self.sheet
+    }
+
+    /// A `<style>` element with the collected rules to embed in the html.
+    ///
+    /// The ids of the entries are listed in its `data-yewcss` attribute, so the client side injector does not insert them again.
+    pub fn to_style_tag(&self) -> String {
+        let ids: Vec<&str> = self.sheet.entries().map(|(id, _)| id).collect();
+        // `</` could end the style element early
+        let css = self.sheet.to_string().replace("</", "<\\/");
+        format!("<style data-yewcss=\"{}\">{css}</style>", escape_attribute(&ids.join(" ")))
+    }
+
+    fn start(&mut self) {
+        let sheet = std::mem::take(&mut self.sheet);
+        ACTIVE.with(|active| active.borrow_mut().push(sheet));
+    }
+
+    fn stop(&mut self) {
+        self.sheet = ACTIVE.with(|active| active.borrow_mut().pop()).unwrap_or_default();
+    }
+}
+
+/// Stops the collector when it is dropped, also if the closure or future panics
+struct Recording<'a>(&'a mut StyleCollector);
+
+impl<'a> Recording<'a> {
+    fn start(collector: &'a mut StyleCollector) -> Self {
+        collector.start();
+        Self(collector)
+    }
+}
+
+impl Drop for Recording<'_> {
+    fn drop(&mut self) {
+        self.0.stop();
+    }
+}
+
+/// The future returned by `StyleCollector::collect_async`
+pub struct Collecting<'a, F: Future> {
+    collector: &'a mut StyleCollector,
+    future: Pin<Box<F>>,
+}
+
+impl<'a, F: Future> Future for Collecting<'a, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _recording = Recording::start(this.collector);
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// Adds the rules to the collectors that are currently recording
+pub(crate) fn record(id: &str, rules: &[String]) {
+    ACTIVE.with(|active| {
+        for sheet in active.borrow_mut().iter_mut().filter(|sheet| !sheet.contains(id)) {
+            sheet.insert(id, rules.to_vec());
+        }
+    });
+}
+
+fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{css, style::{animation::Keyframes, IntoCss}, stylesheet::StyleInjector};
+
+    use super::*;
+
+    #[test]
+    fn only_styles_used_while_collecting_are_recorded() {
+        let before = css!{ width: 10; }.class_name();
+        css!{ width: 20; }.class_name();
+        let mut collector = StyleCollector::new();
+        let inside = collector.collect(|| css!{ width: 30; }.class_name());
+        assert_eq!(collector.sheet().entries().map(|(id, _)| id).collect::<Vec<_>>(), [inside.as_str()]);
+
+        // a class registered before is recorded again when it is used
+        collector.collect(|| css!{ width: 10; }.class_name());
+        assert!(collector.sheet().contains(before.as_str()));
+        assert_eq!(collector.sheet().entries().count(), 2);
+    }
+
+    #[test]
+    fn injected_blocks_are_recorded() {
+        let mut collector = StyleCollector::new();
+        let mut injector = StyleInjector::text();
+        injector.inject(".a", &css!{ width: 10; });
+        collector.collect(|| injector.inject(".a", &css!{ width: 10; }));
+        assert_eq!(collector.sheet().to_string(), ".a {width: 10px;}\n");
+    }
+
+    #[test]
+    fn keyframes_are_recorded() {
+        let mut collector = StyleCollector::new();
+        let name = collector.collect(|| Keyframes::new("spin", vec![(0.0, css!{ width: 0; })]).register());
+        assert!(collector.sheet().to_string().starts_with(&format!("@keyframes {}", name.into_css())));
+    }
+
+    #[test]
+    fn nested_collectors_record_into_each_other() {
+        let mut outer = StyleCollector::new();
+        let mut inner = StyleCollector::new();
+        outer.collect(|| {
+            css!{ width: 10; }.class_name();
+            inner.collect(|| css!{ width: 20; }.class_name());
+        });
+        assert_eq!(outer.sheet().entries().count(), 2);
+        assert_eq!(inner.sheet().entries().count(), 1);
+    }
+
+    #[test]
+    fn a_panic_stops_the_collector() {
+        let mut collector = StyleCollector::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| collector.collect(|| {
+            css!{ width: 10; }.class_name();
+            panic!("render failed");
+        })));
+        assert!(result.is_err());
+        css!{ width: 20; }.class_name();
+        assert_eq!(collector.sheet().entries().count(), 1);
+    }
+
+    #[test]
+    fn style_tag_lists_the_ids_and_escapes_the_css() {
+        let mut collector = StyleCollector::new();
+        collector.collect(|| record("a\"b", &["x::after {content: \"</style>\";}".to_string()]));
+        assert_eq!(collector.to_style_tag(), "<style data-yewcss=\"a&quot;b\">x::after {content: \"<\\/style>\";}\n</style>");
+    }
+}